@@ -1096,6 +1096,8 @@ fn _process(t: &OsStr) -> ProcessBuilder {
         .env_remove("USER") // not set on some rust-lang docker images
         .env_remove("MFLAGS")
         .env_remove("MAKEFLAGS")
+        .env_remove("PAGER")
+        .env_remove("CARGO_PAGER")
         .env_remove("GIT_AUTHOR_NAME")
         .env_remove("GIT_AUTHOR_EMAIL")
         .env_remove("GIT_COMMITTER_NAME")
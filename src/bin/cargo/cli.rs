@@ -13,23 +13,7 @@ pub fn main(config: &mut Config) -> CliResult {
     // In general, try to avoid loading config values unless necessary (like
     // the [alias] table).
 
-    if commands::help::handle_embedded_help(config) {
-        return Ok(());
-    }
-
-    let args = match cli().get_matches_safe() {
-        Ok(args) => args,
-        Err(e) => {
-            if e.kind == clap::ErrorKind::UnrecognizedSubcommand {
-                // An unrecognized subcommand might be an external subcommand.
-                let cmd = &e.info.as_ref().unwrap()[0].to_owned();
-                return super::execute_external_subcommand(config, cmd, &[cmd, "--help"])
-                    .map_err(|_| e.into());
-            } else {
-                return Err(e.into());
-            }
-        }
-    };
+    let args = cli().get_matches_safe()?;
 
     if args.value_of("unstable-features") == Some("help") {
         let options = CliUnstable::help();
@@ -290,7 +274,7 @@ impl GlobalArgs {
     }
 }
 
-fn cli() -> App {
+pub fn cli() -> App {
     let is_rustup = std::env::var_os("RUSTUP_HOME").is_some();
     let usage = if is_rustup {
         "cargo [+toolchain] [OPTIONS] [SUBCOMMAND]"
@@ -303,6 +287,7 @@ fn cli() -> App {
             AppSettings::DeriveDisplayOrder,
             AppSettings::VersionlessSubcommands,
             AppSettings::AllowExternalSubcommands,
+            AppSettings::DisableHelpSubcommand,
         ])
         .usage(usage)
         .template(
@@ -1,8 +1,9 @@
-use crate::aliased_command;
+use crate::command_prelude::*;
+use crate::{aliased_command, BUILTIN_ALIASES};
 use cargo::util::errors::CargoResult;
-use cargo::Config;
 use cargo_util::paths::resolve_executable;
 use flate2::read::GzDecoder;
+use serde::Serialize;
 use std::ffi::OsString;
 use std::io::Read;
 use std::io::Write;
@@ -10,80 +11,234 @@ use std::path::Path;
 
 const COMPRESSED_MAN: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/man.tgz"));
 
-/// Checks if the `help` command is being issued.
-///
-/// This runs before clap processing, because it needs to intercept the `help`
-/// command if a man page is available.
-///
-/// Returns `true` if a man page was displayed. In this case, Cargo should
-/// exit.
-pub fn handle_embedded_help(config: &Config) -> bool {
-    match try_help(config) {
-        Ok(true) => true,
-        Ok(false) => false,
-        Err(e) => {
-            log::warn!("man failed: {:?}", e);
-            false
+pub fn cli() -> App {
+    subcommand("help")
+        .about("Displays help for a cargo subcommand")
+        .setting(AppSettings::AllowInvalidUtf8)
+        .arg(Arg::with_name("COMMAND").multiple(true))
+        .arg(
+            opt(
+                "text",
+                "Print the plain-text man page instead of using man or a pager",
+            )
+            .requires("COMMAND"),
+        )
+        .arg(
+            opt(
+                "man-path",
+                "Write the man page to a file and print the path to it",
+            )
+            .requires("COMMAND"),
+        )
+        .arg(
+            opt("list-json", "Print the built-in commands as JSON")
+                .conflicts_with_all(&["COMMAND", "text", "man-path"]),
+        )
+}
+
+pub fn exec(config: &mut Config, args: &ArgMatches<'_>) -> CliResult {
+    if args.is_present("list-json") {
+        print_command_list(config)?;
+        return Ok(());
+    }
+    let command = match args.values_of_lossy("COMMAND") {
+        Some(command) => command,
+        None => {
+            crate::cli::cli().print_help()?;
+            return Ok(());
+        }
+    };
+    let subcommand = &command[0];
+    let text = args.is_present("text");
+    let man_path = args.is_present("man-path");
+    if try_help(config, subcommand, text, man_path)? {
+        return Ok(());
+    }
+    match check_alias(config, subcommand) {
+        // A built-in command without a man page, use clap's help instead.
+        Some(alias) => {
+            let err = crate::cli::cli()
+                .get_matches_from_safe(vec!["cargo", alias[0].as_str(), "--help"])
+                .unwrap_err();
+            Err(err.into())
+        }
+        // This might be an external subcommand. Any nested subcommands are
+        // forwarded too, so `cargo help foo bar` runs `cargo-foo foo bar --help`.
+        None => {
+            let mut ext_args: Vec<&str> = command.iter().map(String::as_str).collect();
+            ext_args.push("--help");
+            super::super::execute_external_subcommand(config, subcommand, &ext_args)
         }
     }
 }
 
-fn try_help(config: &Config) -> CargoResult<bool> {
-    let mut args = std::env::args_os()
-        .skip(1)
-        .skip_while(|arg| arg.to_str().map_or(false, |s| s.starts_with('-')));
-    if !args
-        .next()
-        .map_or(false, |arg| arg.to_str() == Some("help"))
-    {
-        return Ok(false);
-    }
-    let subcommand = match args.next() {
-        Some(arg) => arg,
-        None => return Ok(false),
-    };
-    let subcommand = match subcommand.to_str() {
-        Some(s) => s,
-        None => return Ok(false),
-    };
+/// Displays the man page for the given subcommand.
+///
+/// Returns `true` if a man page was displayed, or `false` if the command is
+/// not a built-in command (or alias) with a man page.
+fn try_help(config: &Config, subcommand: &str, text: bool, man_path: bool) -> CargoResult<bool> {
     // Check if this is a built-in command (or alias);
-    let subcommand = match check_alias(config, subcommand) {
-        Some(s) => s,
+    let alias = match check_alias(config, subcommand) {
+        Some(alias) => alias,
         None => return Ok(false),
     };
-    if resolve_executable(Path::new("man")).is_ok() {
+    if alias.len() > 1 {
+        // The man page only covers the command, not the extra arguments.
+        config.shell().note(format!(
+            "`{}` is aliased to `{}`, showing help for `{}`",
+            subcommand,
+            alias.join(" "),
+            alias[0]
+        ))?;
+    }
+    let subcommand = alias.into_iter().next().unwrap();
+    if man_path {
         let man = match extract_man(&subcommand, "1") {
             Some(man) => man,
             None => return Ok(false),
         };
-        write_and_spawn(&subcommand, &man, "man")?;
+        let (_, path) = write_man(&subcommand, &man)?.keep()?;
+        drop(writeln!(std::io::stdout(), "{}", path.display()));
+        return Ok(true);
+    }
+    // The text goes to stdout, so only wrap it when stdout is a terminal.
+    // Stdout has no width query of its own; assume it shares stderr's terminal.
+    let width = if atty::is(atty::Stream::Stdout) {
+        config.shell().err_width().diagnostic_terminal_width()
     } else {
+        None
+    };
+    if text {
         let txt = match extract_man(&subcommand, "txt") {
             Some(txt) => txt,
             None => return Ok(false),
         };
-        if resolve_executable(Path::new("less")).is_ok() {
-            write_and_spawn(&subcommand, &txt, "less")?;
-        } else if resolve_executable(Path::new("more")).is_ok() {
-            write_and_spawn(&subcommand, &txt, "more")?;
-        } else {
-            drop(std::io::stdout().write_all(&txt));
+        print_text(&txt, width);
+        return Ok(true);
+    }
+    // `CARGO_PAGER` takes precedence over `man`, while `PAGER` only replaces
+    // the `less`/`more` fallback. An empty value disables paging.
+    let cargo_pager = std::env::var("CARGO_PAGER").ok();
+    if cargo_pager.is_none() && resolve_executable(Path::new("man")).is_ok() {
+        let man = match extract_man(&subcommand, "1") {
+            Some(man) => man,
+            None => return Ok(false),
+        };
+        write_and_spawn(&subcommand, &man, "man", width)?;
+    } else {
+        let txt = match extract_man(&subcommand, "txt") {
+            Some(txt) => txt,
+            None => return Ok(false),
+        };
+        let pager = cargo_pager.or_else(|| std::env::var("PAGER").ok());
+        match pager {
+            Some(pager) if !pager.trim().is_empty() => {
+                write_and_spawn(&subcommand, &txt, &pager, width)?
+            }
+            Some(_) => print_text(&txt, width),
+            None if resolve_executable(Path::new("less")).is_ok() => {
+                write_and_spawn(&subcommand, &txt, "less", width)?
+            }
+            None if resolve_executable(Path::new("more")).is_ok() => {
+                write_and_spawn(&subcommand, &txt, "more", width)?
+            }
+            None => print_text(&txt, width),
         }
     }
     Ok(true)
 }
 
+/// Prints a plain-text man page to stdout without a pager.
+fn print_text(txt: &[u8], width: Option<usize>) {
+    match width {
+        Some(width) => {
+            let txt = wrap_text(&String::from_utf8_lossy(txt), width);
+            drop(std::io::stdout().write_all(txt.as_bytes()));
+        }
+        None => drop(std::io::stdout().write_all(txt)),
+    }
+}
+
+/// Wraps lines of a plain-text man page that are wider than `width`,
+/// keeping the indentation of the original line on continuation lines.
+fn wrap_text(txt: &str, width: usize) -> String {
+    let mut result = String::with_capacity(txt.len());
+    for line in txt.lines() {
+        if line.chars().count() <= width {
+            result.push_str(line);
+            result.push('\n');
+            continue;
+        }
+        let indent = &line[..line.len() - line.trim_start().len()];
+        let mut current = String::from(indent);
+        for word in line.split_whitespace() {
+            let len = current.chars().count();
+            if len > indent.len() && len + 1 + word.chars().count() > width {
+                result.push_str(&current);
+                result.push('\n');
+                current = String::from(indent);
+            }
+            if current.len() > indent.len() {
+                current.push(' ');
+            }
+            current.push_str(word);
+        }
+        result.push_str(&current);
+        result.push('\n');
+    }
+    result
+}
+
+#[derive(Serialize)]
+struct CommandListing {
+    name: String,
+    about: Option<String>,
+    aliases: Vec<String>,
+    hidden: bool,
+}
+
+/// Prints the built-in commands as JSON for `cargo help --list-json`.
+fn print_command_list(config: &Config) -> CargoResult<()> {
+    let commands: Vec<_> = super::builtin()
+        .iter()
+        .map(|cmd| {
+            let name = cmd.get_name().to_string();
+            let mut aliases: Vec<String> = cmd
+                .p
+                .meta
+                .aliases
+                .iter()
+                .flatten()
+                .map(|(alias, _visible)| alias.to_string())
+                .collect();
+            aliases.extend(
+                BUILTIN_ALIASES
+                    .iter()
+                    .filter(|alias| alias.1 == name)
+                    .map(|alias| alias.0.to_string()),
+            );
+            CommandListing {
+                about: cmd.p.meta.about.map(|s| s.to_string()),
+                aliases,
+                hidden: cmd.p.is_set(AppSettings::Hidden),
+                name,
+            }
+        })
+        .collect();
+    config.shell().print_json(&commands)
+}
+
 /// Checks if the given subcommand is a built-in command (possibly via an alias).
 ///
-/// Returns None if it is not a built-in command.
-fn check_alias(config: &Config, subcommand: &str) -> Option<String> {
+/// Returns the command followed by any arguments the alias adds, or None if
+/// it is not a built-in command.
+fn check_alias(config: &Config, subcommand: &str) -> Option<Vec<String>> {
     if super::builtin_exec(subcommand).is_some() {
-        return Some(subcommand.to_string());
+        return Some(vec![subcommand.to_string()]);
     }
     match aliased_command(config, subcommand) {
         Ok(Some(alias)) => {
-            let alias = alias.into_iter().next()?;
-            if super::builtin_exec(&alias).is_some() {
+            if super::builtin_exec(alias.first()?).is_some() {
                 Some(alias)
             } else {
                 None
@@ -115,23 +270,73 @@ fn extract_man(subcommand: &str, extension: &str) -> Option<Vec<u8>> {
     None
 }
 
-/// Write the contents of a man page to disk and spawn the given command to
-/// display it.
-fn write_and_spawn(name: &str, contents: &[u8], command: &str) -> CargoResult<()> {
+/// Write the contents of a man page to a temporary file.
+///
+/// The file is removed when the returned handle is dropped.
+fn write_man(name: &str, contents: &[u8]) -> CargoResult<tempfile::NamedTempFile> {
     let prefix = format!("cargo-{}.", name);
     let mut tmp = tempfile::Builder::new().prefix(&prefix).tempfile()?;
     let f = tmp.as_file_mut();
     f.write_all(contents)?;
     f.flush()?;
+    Ok(tmp)
+}
+
+/// Write the contents of a man page to disk and spawn the given command to
+/// display it.
+///
+/// The command may include arguments separated by whitespace, such as
+/// `less -R`. It is not run through a shell, so quoting is not supported.
+fn write_and_spawn(
+    name: &str,
+    contents: &[u8],
+    command: &str,
+    width: Option<usize>,
+) -> CargoResult<()> {
+    let tmp = write_man(name, contents)?;
     let path = tmp.path();
     // Use a path relative to the temp directory so that it can work on
     // cygwin/msys systems which don't handle windows-style paths.
     let mut relative_name = std::ffi::OsString::from("./");
     relative_name.push(path.file_name().unwrap());
-    let mut cmd = std::process::Command::new(command)
+    let mut command = command.split_whitespace();
+    let mut cmd = std::process::Command::new(command.next().unwrap());
+    cmd.args(command)
         .arg(relative_name)
-        .current_dir(path.parent().unwrap())
-        .spawn()?;
+        .current_dir(path.parent().unwrap());
+    if let Some(width) = width {
+        if std::env::var_os("MANWIDTH").is_none() {
+            cmd.env("MANWIDTH", width.to_string());
+        }
+    }
+    let mut cmd = cmd.spawn()?;
     drop(cmd.wait());
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::wrap_text;
+
+    #[test]
+    fn wrap_text_keeps_short_lines() {
+        let txt = "NAME\n       cargo-build\n\n";
+        assert_eq!(wrap_text(txt, 20), txt);
+    }
+
+    #[test]
+    fn wrap_text_keeps_indentation() {
+        assert_eq!(
+            wrap_text("    one two three four\n", 14),
+            "    one two\n    three four\n"
+        );
+    }
+
+    #[test]
+    fn wrap_text_long_word() {
+        assert_eq!(
+            wrap_text("  a --very-long-option-name b\n", 10),
+            "  a\n  --very-long-option-name\n  b\n"
+        );
+    }
+}
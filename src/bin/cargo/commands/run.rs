@@ -33,10 +33,30 @@ pub fn cli() -> App {
 pub fn exec(config: &mut Config, args: &ArgMatches<'_>) -> CliResult {
     let ws = args.workspace(config)?;
 
+    // `--example` without a name runs the `default-example` of the selected
+    // package, if there is exactly one.
+    let default_example = if args.is_present_with_zero_values("example") {
+        let default_examples: Vec<_> = args
+            .packages_from_flags()?
+            .get_packages(&ws)?
+            .iter()
+            .filter_map(|pkg| pkg.manifest().default_example())
+            .map(|example| example.to_owned())
+            .collect();
+        if default_examples.len() == 1 {
+            default_examples.into_iter().next()
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
     let mut compile_opts = args.compile_options(
         config,
         CompileMode::Build,
-        Some(&ws),
+        // Skip listing the available examples if there is a default one.
+        default_example.as_ref().map_or(Some(&ws), |_| None),
         ProfileChecking::Checked,
     )?;
 
@@ -51,7 +71,20 @@ pub fn exec(config: &mut Config, args: &ArgMatches<'_>) -> CliResult {
         }
     }
 
-    if !args.is_present("example") && !args.is_present("bin") {
+    if let Some(example) = default_example {
+        compile_opts.filter = CompileFilter::from_raw_arguments(
+            false,
+            vec![],
+            false,
+            vec![],
+            false,
+            vec![example],
+            false,
+            vec![],
+            false,
+            false,
+        );
+    } else if !args.is_present("example") && !args.is_present("bin") {
         let default_runs: Vec<_> = compile_opts
             .spec
             .get_packages(&ws)?
@@ -43,6 +43,9 @@ pub struct Context<'a, 'cfg> {
     pub fingerprints: HashMap<Unit, Arc<Fingerprint>>,
     /// Cache of file mtimes to reduce filesystem hits.
     pub mtime_cache: HashMap<PathBuf, FileTime>,
+    /// Cache of `-C profile-use` file content hashes, so that each file is
+    /// only read once per build. `None` if the file couldn't be read.
+    pub profile_use_hashes: HashMap<PathBuf, Option<u64>>,
     /// A set used to track which units have been compiled.
     /// A unit may appear in the job graph multiple times as a dependency of
     /// multiple packages, but it only needs to run once.
@@ -111,6 +114,7 @@ impl<'a, 'cfg> Context<'a, 'cfg> {
             build_script_outputs: Arc::new(Mutex::new(BuildScriptOutputs::default())),
             fingerprints: HashMap::new(),
             mtime_cache: HashMap::new(),
+            profile_use_hashes: HashMap::new(),
             compiled: HashSet::new(),
             build_scripts: HashMap::new(),
             build_explicit_deps: HashMap::new(),
@@ -330,6 +330,7 @@ use serde::ser;
 use serde::{Deserialize, Serialize};
 
 use crate::core::compiler::unit_graph::UnitDep;
+use crate::core::profiles::Pgo;
 use crate::core::Package;
 use crate::util;
 use crate::util::errors::CargoResult;
@@ -1255,6 +1256,29 @@ fn calculate(cx: &mut Context<'_, '_>, unit: &Unit) -> CargoResult<Arc<Fingerpri
     Ok(fingerprint)
 }
 
+/// Returns the profile data files passed to rustc with `-C profile-use`,
+/// either from the `pgo-use` profile setting or from `RUSTFLAGS`.
+fn profile_use_paths(cx: &Context<'_, '_>, unit: &Unit, flags: &[String]) -> Vec<PathBuf> {
+    let root = cx.bcx.ws.root();
+    let mut result = Vec::new();
+    if let Pgo::Use(path) = unit.profile.pgo {
+        result.push(root.join(path.as_str()));
+    }
+    let mut flags = flags.iter().map(String::as_str);
+    while let Some(flag) = flags.next() {
+        let value = match flag {
+            "-C" | "--codegen" => flags.next(),
+            _ => flag
+                .strip_prefix("-C")
+                .or_else(|| flag.strip_prefix("--codegen=")),
+        };
+        if let Some(path) = value.and_then(|v| v.strip_prefix("profile-use=")) {
+            result.push(root.join(path));
+        }
+    }
+    result
+}
+
 /// Calculate a fingerprint for a "normal" unit, or anything that's not a build
 /// script. This is an internal helper of `calculate`, don't call directly.
 fn calculate_normal(cx: &mut Context<'_, '_>, unit: &Unit) -> CargoResult<Fingerprint> {
@@ -1314,6 +1338,8 @@ fn calculate_normal(cx: &mut Context<'_, '_>, unit: &Unit) -> CargoResult<Finger
         unit.mode,
         cx.bcx.extra_args_for(unit),
         cx.lto[unit],
+        // Not part of the profile's hash, see `Profile::comparable`.
+        unit.profile.pgo,
     ));
     // Include metadata since it is exposed as environment variables.
     let m = unit.pkg.manifest().metadata();
@@ -1330,6 +1356,16 @@ fn calculate_normal(cx: &mut Context<'_, '_>, unit: &Unit) -> CargoResult<Finger
     if let Some(allow_features) = &cx.bcx.config.cli_unstable().allow_features {
         allow_features.hash(&mut config);
     }
+    // Profile data can be updated in place without its path changing.
+    for path in profile_use_paths(cx, unit, &extra_flags) {
+        let hash = cx
+            .profile_use_hashes
+            .entry(path)
+            .or_insert_with_key(|path| StableHasher::hash_file(path).ok());
+        if let Some(hash) = hash {
+            hash.hash(&mut config);
+        }
+    }
     let compile_kind = unit.kind.fingerprint_hash();
     Ok(Fingerprint {
         rustc: util::hash_u64(&cx.bcx.rustc().verbose_version),
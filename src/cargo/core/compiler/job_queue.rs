@@ -525,6 +525,11 @@ impl<'cfg> DrainState<'cfg> {
             // length 1, as otherwise there can't be a client waiting to be sent
             // on, so tokens.len() must also be at least one.
             let token = self.tokens.pop().unwrap();
+            info!(
+                "jobserver token granted to {:?}, {} extra tokens held",
+                id,
+                self.tokens.len()
+            );
             self.rustc_tokens
                 .entry(id)
                 .or_insert_with(Vec::new)
@@ -614,6 +619,10 @@ impl<'cfg> DrainState<'cfg> {
             Message::Token(acquired_token) => {
                 let token = acquired_token.with_context(|| "failed to acquire jobserver token")?;
                 self.tokens.push(token);
+                info!(
+                    "jobserver token acquired, {} extra tokens held",
+                    self.tokens.len()
+                );
             }
             Message::NeedsToken(id) => {
                 log::info!("queue token request");
@@ -639,6 +648,11 @@ impl<'cfg> DrainState<'cfg> {
                     .expect("no tokens associated");
                 self.tokens
                     .push(rustc_tokens.pop().expect("rustc releases token it has"));
+                info!(
+                    "jobserver token released by {:?}, {} extra tokens held",
+                    id,
+                    self.tokens.len()
+                );
             }
         }
 
@@ -670,6 +684,10 @@ impl<'cfg> DrainState<'cfg> {
         if events.is_empty() {
             loop {
                 self.tick_progress();
+                let extra = self.tokens.len().saturating_sub(self.active.len() - 1);
+                if extra > 0 {
+                    info!("returning {} unused jobserver tokens", extra);
+                }
                 self.tokens.truncate(self.active.len() - 1);
                 match self.messages.pop(Duration::from_millis(500)) {
                     Some(message) => {
@@ -52,7 +52,7 @@ use self::unit_graph::UnitDep;
 use crate::core::compiler::future_incompat::FutureIncompatReport;
 pub use crate::core::compiler::unit::{Unit, UnitInterner};
 use crate::core::manifest::TargetSourcePath;
-use crate::core::profiles::{PanicStrategy, Profile, Strip};
+use crate::core::profiles::{PanicStrategy, Pgo, Profile, Strip};
 use crate::core::{Feature, PackageId, Target};
 use crate::util::errors::{CargoResult, VerboseError};
 use crate::util::interning::InternedString;
@@ -803,6 +803,7 @@ fn build_base_args(
         ref panic,
         incremental,
         strip,
+        pgo,
         ..
     } = unit.profile;
     let test = unit.mode.is_any_test();
@@ -951,6 +952,21 @@ fn build_base_args(
         cmd.arg("-Z").arg(format!("strip={}", strip));
     }
 
+    // Profile data paths are relative to the workspace root.
+    match pgo {
+        Pgo::None => {}
+        Pgo::Generate(dir) => {
+            let mut arg = OsString::from("profile-generate=");
+            arg.push(bcx.ws.root().join(dir.as_str()));
+            cmd.arg("-C").arg(arg);
+        }
+        Pgo::Use(path) => {
+            let mut arg = OsString::from("profile-use=");
+            arg.push(bcx.ws.root().join(path.as_str()));
+            cmd.arg("-C").arg(arg);
+        }
+    }
+
     if unit.is_std {
         // -Zforce-unstable-if-unmarked prevents the accidental use of
         // unstable crates within the sysroot (such as "extern crate libc" or
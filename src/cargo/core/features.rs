@@ -101,7 +101,7 @@ use cargo_util::ProcessBuilder;
 use serde::{Deserialize, Serialize};
 
 use crate::util::errors::CargoResult;
-use crate::util::{indented_lines, iter_join};
+use crate::util::{indented_lines, iter_join, lev_distance};
 use crate::Config;
 
 pub const HIDDEN: &str = "";
@@ -249,20 +249,57 @@ impl FromStr for Edition {
             "2015" => Ok(Edition::Edition2015),
             "2018" => Ok(Edition::Edition2018),
             "2021" => Ok(Edition::Edition2021),
-            s if s.parse().map_or(false, |y: u16| y > 2021 && y < 2050) => bail!(
-                "this version of Cargo is older than the `{}` edition, \
-                 and only supports `2015`, `2018`, and `2021` editions.",
-                s
-            ),
+            s if s.parse().map_or(false, |y: u16| y > 2021 && y < 2050) => {
+                // Editions come out every three years, so a year before the
+                // next one is most likely a typo rather than a newer edition.
+                let suggestion = if s.parse().map_or(false, |y: u16| y < 2024) {
+                    edition_suggestion(s)
+                } else {
+                    String::new()
+                };
+                bail!(
+                    "this version of Cargo is older than the `{}` edition, \
+                     and only supports `2015`, `2018`, and `2021` editions.{}",
+                    s,
+                    suggestion
+                )
+            }
             s => bail!(
                 "supported edition values are `2015`, `2018`, or `2021`, \
-                 but `{}` is unknown",
-                s
+                 but `{}` is unknown{}",
+                s,
+                edition_suggestion(s)
             ),
         }
     }
 }
 
+/// Suggests the known edition that `s` is a one-character typo of, noting if
+/// it isn't stable yet.
+///
+/// Edition years all look alike, so anything further off isn't suggested.
+/// Several editions are often one character away (`2019` from both `2015`
+/// and `2018`), in which case the numerically nearest one wins.
+fn edition_suggestion(s: &str) -> String {
+    let year = s.parse::<i32>().ok();
+    let closest = Edition::CLI_VALUES
+        .iter()
+        .filter(|e| lev_distance::lev_distance(s, e) <= 1)
+        .min_by_key(|e| year.map_or(0, |year| (year - e.parse::<i32>().unwrap()).abs()))
+        .and_then(|e| e.parse::<Edition>().ok());
+    match closest {
+        Some(edition) if edition.is_stable() => {
+            format!("\n\n\tDid you mean `{}`?", edition)
+        }
+        Some(edition) => format!(
+            "\n\n\tDid you mean `{}`? It is unstable and requires \
+             `cargo-features = [\"edition{}\"]`",
+            edition, edition
+        ),
+        None => String::new(),
+    }
+}
+
 #[derive(PartialEq)]
 enum Status {
     Stable,
@@ -393,6 +430,12 @@ features! {
 
     // Allow to specify per-package targets (compile kinds)
     (unstable, per_package_target, "", "reference/unstable.html#per-package-target"),
+
+    // "default-example" manifest option
+    (unstable, default_example, "", "reference/unstable.html#default-example"),
+
+    // Profile-guided optimization settings in profiles.
+    (unstable, profile_pgo, "", "reference/unstable.html#profile-pgo"),
 }
 
 const PUBLISH_LOCKFILE_REMOVED: &str = "The publish-lockfile key in Cargo.toml \
@@ -51,6 +51,7 @@ pub struct Manifest {
     rust_version: Option<String>,
     im_a_teapot: Option<bool>,
     default_run: Option<String>,
+    default_example: Option<String>,
     metabuild: Option<Vec<String>>,
     resolve_behavior: Option<ResolveBehavior>,
 }
@@ -386,6 +387,7 @@ impl Manifest {
         rust_version: Option<String>,
         im_a_teapot: Option<bool>,
         default_run: Option<String>,
+        default_example: Option<String>,
         original: Rc<TomlManifest>,
         metabuild: Option<Vec<String>>,
         resolve_behavior: Option<ResolveBehavior>,
@@ -412,6 +414,7 @@ impl Manifest {
             original,
             im_a_teapot,
             default_run,
+            default_example,
             metabuild,
             resolve_behavior,
         }
@@ -524,6 +527,12 @@ impl Manifest {
                 })?;
         }
 
+        if self.default_example.is_some() {
+            self.unstable_features
+                .require(Feature::default_example())
+                .with_context(|| "the `package.default-example` manifest key is unstable")?;
+        }
+
         Ok(())
     }
 
@@ -552,6 +561,10 @@ impl Manifest {
         self.default_run.as_deref()
     }
 
+    pub fn default_example(&self) -> Option<&str> {
+        self.default_example.as_deref()
+    }
+
     pub fn metabuild(&self) -> Option<&Vec<String>> {
         self.metabuild.as_ref()
     }
@@ -611,6 +611,12 @@ fn merge_profile(profile: &mut Profile, toml: &TomlProfile) {
         Some(StringOrBool::String(ref n)) if is_off(n.as_str()) => Strip::None,
         Some(StringOrBool::String(ref n)) => Strip::Named(InternedString::new(n)),
     };
+    // These are validated to be mutually exclusive in `TomlProfile::validate`.
+    if let Some(path) = &toml.pgo_generate {
+        profile.pgo = Pgo::Generate(InternedString::new(path));
+    } else if let Some(path) = &toml.pgo_use {
+        profile.pgo = Pgo::Use(InternedString::new(path));
+    }
 }
 
 /// The root profile (dev/release).
@@ -643,6 +649,7 @@ pub struct Profile {
     pub incremental: bool,
     pub panic: PanicStrategy,
     pub strip: Strip,
+    pub pgo: Pgo,
 }
 
 impl Default for Profile {
@@ -661,6 +668,7 @@ impl Default for Profile {
             incremental: false,
             panic: PanicStrategy::Unwind,
             strip: Strip::None,
+            pgo: Pgo::None,
         }
     }
 }
@@ -687,6 +695,7 @@ compact_debug! {
                 incremental
                 panic
                 strip
+                pgo
             )]
         }
     }
@@ -761,6 +770,10 @@ impl Profile {
     /// Compares all fields except `name`, which doesn't affect compilation.
     /// This is necessary for `Unit` deduplication for things like "test" and
     /// "dev" which are essentially the same.
+    ///
+    /// `pgo` is also left out, since this is hashed into `-C metadata` and
+    /// the instrumented and optimized builds must have the same symbol names
+    /// for the profile data to apply. It is tracked by the fingerprint.
     fn comparable(&self) -> impl Hash + Eq {
         (
             self.opt_level,
@@ -843,6 +856,18 @@ impl fmt::Display for Strip {
     }
 }
 
+/// The profile-guided optimization setting.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Pgo {
+    /// Don't generate or use profile data.
+    None,
+    /// Instrument the code to write profile data into this directory.
+    Generate(InternedString),
+    /// Optimize using the merged profile data at this path.
+    Use(InternedString),
+}
+
 /// Flags used in creating `Unit`s to indicate the purpose for the target, and
 /// to ensure the target's dependencies have the correct settings.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -419,6 +419,18 @@ impl<'cfg> Workspace<'cfg> {
     ///
     /// This may be from a virtual crate or an actual crate.
     pub fn root_patch(&self) -> CargoResult<HashMap<Url, Vec<Dependency>>> {
+        self.root_patch_with_warnings(&mut Vec::new())
+    }
+
+    /// Same as `root_patch`, but also collects warnings about `[patch]`
+    /// entries in cargo config that are overridden by the manifest.
+    ///
+    /// `root_patch` is called several times per command, so only the caller
+    /// registering the patches should report these.
+    pub fn root_patch_with_warnings(
+        &self,
+        warnings: &mut Vec<String>,
+    ) -> CargoResult<HashMap<Url, Vec<Dependency>>> {
         let from_manifest = match self.root_maybe() {
             MaybePackage::Package(p) => p.manifest().patch(),
             MaybePackage::Virtual(vm) => vm.patch(),
@@ -445,7 +457,28 @@ impl<'cfg> Workspace<'cfg> {
                         // XXX: should this also take into account version numbers?
                         dep.name_in_toml() == cdep.name_in_toml()
                     }) {
-                        left.swap_remove(i);
+                        let cdep = left.swap_remove(i);
+                        if cdep.source_id() != dep.source_id()
+                            || cdep.version_req() != dep.version_req()
+                        {
+                            let describe = |dep: &Dependency| {
+                                if dep.specified_req() {
+                                    format!("{} ({})", dep.source_id(), dep.version_req())
+                                } else {
+                                    dep.source_id().to_string()
+                                }
+                            };
+                            warnings.push(format!(
+                                "[patch] for `{}` in cargo config is ignored, because the \
+                                 [patch] for it in `{}` takes precedence\n\
+                                 manifest: {}\n\
+                                 config:   {}",
+                                dep.name_in_toml(),
+                                self.root_manifest().display(),
+                                describe(dep),
+                                describe(&cdep),
+                            ));
+                        }
                     }
                 }
                 // Whatever is left does not exist in manifest dependencies.
@@ -612,6 +612,8 @@ pub fn create_bcx<'a, 'cfg>(
         }
     }
 
+    warn_dependency_rust_versions(ws, &unit_graph)?;
+
     let bcx = BuildContext::new(
         ws,
         pkg_set,
@@ -626,6 +628,52 @@ pub fn create_bcx<'a, 'cfg>(
     Ok(bcx)
 }
 
+/// Warns when a workspace member depends on a package whose `rust-version`
+/// is newer than the member's own.
+///
+/// Only dependencies in the unit graph are checked, so optional dependencies
+/// of disabled features are skipped, and dev-dependencies are only checked
+/// when building tests.
+fn warn_dependency_rust_versions(ws: &Workspace<'_>, unit_graph: &UnitGraph) -> CargoResult<()> {
+    fn parse(version: &str) -> Vec<u64> {
+        let mut parts: Vec<u64> = version.split('.').filter_map(|p| p.parse().ok()).collect();
+        parts.resize(3, 0);
+        parts
+    }
+
+    let mut warned = HashSet::new();
+    let mut units: Vec<_> = unit_graph.keys().collect();
+    units.sort();
+    for unit in units {
+        if !ws.is_member(&unit.pkg) {
+            continue;
+        }
+        let version = match unit.pkg.rust_version() {
+            Some(v) => v,
+            None => continue,
+        };
+        for dep in &unit_graph[unit] {
+            let dep_pkg = &dep.unit.pkg;
+            let dep_version = match dep_pkg.rust_version() {
+                Some(v) => v,
+                None => continue,
+            };
+            if dep_pkg.package_id() == unit.pkg.package_id()
+                || parse(dep_version) <= parse(version)
+                || !warned.insert((unit.pkg.package_id(), dep_pkg.package_id()))
+            {
+                continue;
+            }
+            ws.config().shell().warn(format!(
+                "package `{}` has a `rust-version` of {}, but its dependency `{}` \
+                 requires rust-version {}",
+                unit.pkg, version, dep_pkg, dep_version
+            ))?;
+        }
+    }
+    Ok(())
+}
+
 impl FilterRule {
     pub fn new(targets: Vec<String>, all: bool) -> FilterRule {
         if all {
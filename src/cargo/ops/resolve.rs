@@ -246,7 +246,12 @@ pub fn resolve_with_previous<'cfg>(
     // locked.
     let mut avoid_patch_ids = HashSet::new();
     if register_patches {
-        for (url, patches) in ws.root_patch()?.iter() {
+        let mut patch_warnings = Vec::new();
+        let root_patch = ws.root_patch_with_warnings(&mut patch_warnings)?;
+        for warning in patch_warnings {
+            ws.config().shell().warn(warning)?;
+        }
+        for (url, patches) in root_patch.iter() {
             let previous = match previous {
                 Some(r) => r,
                 None => {
@@ -440,7 +445,18 @@ pub fn resolve_with_previous<'cfg>(
         let warnings: Vec<String> = resolved
             .unused_patches()
             .iter()
-            .map(|pkgid| format!("Patch `{}` was not used in the crate graph.", pkgid))
+            .map(|pkgid| {
+                if resolved.iter().any(|id| id.name() == pkgid.name()) {
+                    format!("Patch `{}` was not used in the crate graph.", pkgid)
+                } else {
+                    format!(
+                        "Patch `{}` was not used in the crate graph, \
+                         no package in the graph is named `{}`.",
+                        pkgid,
+                        pkgid.name()
+                    )
+                }
+            })
             .collect();
         if !warnings.is_empty() {
             ws.config().shell().warn(format!(
@@ -124,6 +124,13 @@ impl<'cfg> PathSource<'cfg> {
             exclude_builder.add_line(None, rule)?;
         }
         let ignore_exclude = exclude_builder.build()?;
+        // A negated exclude rule may re-include files inside an excluded
+        // directory, so directories can't be skipped as a whole in that case.
+        let exclude_has_negation = pkg
+            .manifest()
+            .exclude()
+            .iter()
+            .any(|rule| rule.starts_with('!'));
 
         let mut include_builder = GitignoreBuilder::new(root);
         for rule in pkg.manifest().include() {
@@ -136,7 +143,7 @@ impl<'cfg> PathSource<'cfg> {
             if no_include_option {
                 match ignore_exclude.matched_path_or_any_parents(relative_path, is_dir) {
                     Match::None => Ok(true),
-                    Match::Ignore(_) => Ok(false),
+                    Match::Ignore(_) => Ok(is_dir && exclude_has_negation),
                     Match::Whitelist(_) => Ok(true),
                 }
             } else {
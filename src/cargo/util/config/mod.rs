@@ -77,6 +77,7 @@ use crate::util::{FileLock, Filesystem, IntoUrl, IntoUrlWithBase, Rustc};
 use anyhow::{anyhow, bail, format_err, Context as _};
 use cargo_util::paths;
 use curl::easy::Easy;
+use jobserver::FromEnvErrorKind;
 use lazycell::LazyCell;
 use serde::Deserialize;
 use url::Url;
@@ -216,9 +217,25 @@ impl Config {
 
         // This should be called early on in the process, so in theory the
         // unsafety is ok here. (taken ownership of random fds)
+        let mut shell = shell;
         INIT.call_once(|| unsafe {
-            if let Some(client) = jobserver::Client::from_env() {
-                GLOBAL_JOBSERVER = Box::into_raw(Box::new(client));
+            let from_env = jobserver::Client::from_env_ext(true);
+            match from_env.client {
+                Ok(client) => GLOBAL_JOBSERVER = Box::into_raw(Box::new(client)),
+                Err(e) => match e.kind() {
+                    FromEnvErrorKind::NoEnvVar
+                    | FromEnvErrorKind::NoJobserver
+                    | FromEnvErrorKind::Unsupported => {}
+                    _ => {
+                        let (var, value) = from_env.var.unwrap_or_default();
+                        drop(shell.warn(format!(
+                            "failed to connect to the jobserver from environment \
+                             variable `{}={:?}`: {}\n\
+                             falling back to the parallelism of `-j`",
+                            var, value, e
+                        )));
+                    }
+                },
             }
         });
 
@@ -4,14 +4,50 @@
 //! sufficient for cryptographic purposes).
 #![allow(deprecated)]
 
+use std::fs::File;
 use std::hash::{Hasher, SipHasher};
+use std::io::Read;
+use std::path::Path;
 
+use anyhow::Context as _;
+
+use crate::util::errors::CargoResult;
+
+#[derive(Clone)]
 pub struct StableHasher(SipHasher);
 
 impl StableHasher {
     pub fn new() -> StableHasher {
         StableHasher(SipHasher::new())
     }
+
+    /// Hashes `bytes` with a fresh hasher, returning the finished value.
+    pub fn hash_bytes(bytes: &[u8]) -> u64 {
+        let mut hasher = StableHasher::new();
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    /// Hashes the contents of the file at `path` without reading it all into
+    /// memory at once.
+    ///
+    /// This returns the same value as `hash_bytes` on the file's contents.
+    pub fn hash_file(path: &Path) -> CargoResult<u64> {
+        let mut file = File::open(path)
+            .with_context(|| format!("failed to open file `{}`", path.display()))?;
+        let mut hasher = StableHasher::new();
+        let mut buf = vec![0; 64 * 1024];
+        loop {
+            let n = file
+                .read(&mut buf)
+                .with_context(|| format!("failed to read file `{}`", path.display()))?;
+            if n == 0 {
+                break;
+            }
+            hasher.write(&buf[..n]);
+        }
+        Ok(hasher.finish())
+    }
 }
 
 impl Hasher for StableHasher {
@@ -22,3 +58,42 @@ impl Hasher for StableHasher {
         self.0.write(bytes)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::StableHasher;
+    use std::hash::Hasher;
+    use std::io::Write;
+
+    #[test]
+    fn clone_is_independent() {
+        let mut a = StableHasher::new();
+        a.write(b"shared prefix");
+        let mut b = a.clone();
+        a.write(b"left");
+        b.write(b"right");
+        assert_ne!(a.finish(), b.finish());
+        assert_eq!(a.finish(), StableHasher::hash_bytes(b"shared prefixleft"));
+        assert_eq!(b.finish(), StableHasher::hash_bytes(b"shared prefixright"));
+    }
+
+    #[test]
+    fn hash_bytes_matches_write() {
+        let mut hasher = StableHasher::new();
+        hasher.write(b"some bytes");
+        assert_eq!(hasher.finish(), StableHasher::hash_bytes(b"some bytes"));
+    }
+
+    #[test]
+    fn hash_file_matches_hash_bytes() {
+        // Larger than one read buffer, and not a multiple of it.
+        let data: Vec<u8> = (0..200_003u32).map(|i| (i % 251) as u8).collect();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(&data).unwrap();
+        file.flush().unwrap();
+        assert_eq!(
+            StableHasher::hash_file(file.path()).unwrap(),
+            StableHasher::hash_bytes(&data)
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt;
+use std::fs;
 use std::marker::PhantomData;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
@@ -446,6 +447,8 @@ pub struct TomlProfile {
     pub dir_name: Option<InternedString>,
     pub inherits: Option<InternedString>,
     pub strip: Option<StringOrBool>,
+    pub pgo_generate: Option<String>,
+    pub pgo_use: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
@@ -476,7 +479,13 @@ impl<'de> de::Deserialize<'de> for ProfilePackageSpec {
             Ok(ProfilePackageSpec::All)
         } else {
             PackageIdSpec::parse(&string)
-                .map_err(de::Error::custom)
+                .map_err(|e| {
+                    de::Error::custom(format!(
+                        "invalid package ID specification `{}` in profile override \
+                         (use `\"*\"` to match all packages): {}",
+                        string, e
+                    ))
+                })
                 .map(ProfilePackageSpec::Spec)
         }
     }
@@ -562,9 +571,38 @@ impl TomlProfile {
             }
         }
 
+        self.validate_opt_level()?;
+
+        if let Some(StringOrBool::String(lto)) = &self.lto {
+            match lto.as_str() {
+                "true" | "false" => bail!(
+                    "`lto` setting of `\"{}\"` is a string, \
+                     use the boolean `lto = {}` instead",
+                    lto,
+                    lto
+                ),
+                "thin" | "fat" | "off" | "y" | "yes" | "on" | "n" | "no" | "none" => {}
+                _ => bail!(
+                    "`lto` setting of `{}` is not a valid setting, \
+                     must be `true`, `false`, `\"thin\"`, `\"fat\"` or `\"off\"`",
+                    lto
+                ),
+            }
+        }
+
         if self.strip.is_some() {
             features.require(Feature::strip())?;
         }
+
+        if self.pgo_generate.is_some() || self.pgo_use.is_some() {
+            features.require(Feature::profile_pgo())?;
+            if self.pgo_generate.is_some() && self.pgo_use.is_some() {
+                bail!(
+                    "`pgo-generate` and `pgo-use` cannot both be set in profile `{}`",
+                    name
+                );
+            }
+        }
         Ok(())
     }
 
@@ -597,6 +635,19 @@ impl TomlProfile {
         Ok(())
     }
 
+    fn validate_opt_level(&self) -> CargoResult<()> {
+        if let Some(TomlOptLevel(opt_level)) = &self.opt_level {
+            if !matches!(opt_level.as_str(), "0" | "1" | "2" | "3" | "s" | "z") {
+                bail!(
+                    "`opt-level` setting of `{}` is not a valid setting, \
+                     must be `0`, `1`, `2`, `3`, `s` or `z`",
+                    opt_level
+                );
+            }
+        }
+        Ok(())
+    }
+
     fn validate_override(&self, which: &str) -> CargoResult<()> {
         if self.package.is_some() {
             bail!("package-specific profiles cannot be nested");
@@ -613,6 +664,7 @@ impl TomlProfile {
         if self.rpath.is_some() {
             bail!("`rpath` may not be specified in a `{}` profile", which)
         }
+        self.validate_opt_level()?;
         Ok(())
     }
 
@@ -692,6 +744,13 @@ impl TomlProfile {
         if let Some(v) = &profile.strip {
             self.strip = Some(v.clone());
         }
+
+        // Generating and using profile data are mutually exclusive, so the
+        // later layer replaces both settings.
+        if profile.pgo_generate.is_some() || profile.pgo_use.is_some() {
+            self.pgo_generate = profile.pgo_generate.clone();
+            self.pgo_use = profile.pgo_use.clone();
+        }
     }
 }
 
@@ -836,6 +895,7 @@ pub struct TomlProject {
     autotests: Option<bool>,
     autobenches: Option<bool>,
     default_run: Option<String>,
+    default_example: Option<String>,
 
     // Package metadata.
     description: Option<String>,
@@ -888,6 +948,7 @@ impl TomlManifest {
     /// Prepares the manifest for publishing.
     // - Path and git components of dependency specifications are removed.
     // - License path is updated to point within the package.
+    // - Profile overrides for dev-dependencies that get removed are dropped.
     pub fn prepare_for_publish(
         &self,
         ws: &Workspace<'_>,
@@ -918,11 +979,15 @@ impl TomlManifest {
                 );
             }
         }
+        let profile = match &self.profile {
+            Some(profiles) => Some(prune_profile_overrides(config, self, profiles)?),
+            None => None,
+        };
         let all = |_d: &TomlDependency| true;
         return Ok(TomlManifest {
             package: Some(package),
             project: None,
-            profile: self.profile.clone(),
+            profile,
             lib: self.lib.clone(),
             bin: self.bin.clone(),
             example: self.example.clone(),
@@ -986,6 +1051,90 @@ impl TomlManifest {
             cargo_features: self.cargo_features.clone(),
         });
 
+        /// Drops `[profile.*.package.<name>]` overrides for dev-dependencies
+        /// that are removed from the published manifest, since they would
+        /// no longer match any package.
+        fn prune_profile_overrides(
+            config: &Config,
+            me: &TomlManifest,
+            profiles: &TomlProfiles,
+        ) -> CargoResult<TomlProfiles> {
+            let mut kept = HashSet::new();
+            let mut removed = HashSet::new();
+            let mut visit = |deps: Option<&BTreeMap<String, TomlDependency>>, is_dev: bool| {
+                for (name, dep) in deps.into_iter().flatten() {
+                    let package = match dep {
+                        TomlDependency::Detailed(d) => d.package.as_deref().unwrap_or(name),
+                        TomlDependency::Simple(_) => name,
+                    };
+                    if is_dev && !dep.is_version_specified() {
+                        removed.insert(package.to_string());
+                    } else {
+                        kept.insert(package.to_string());
+                    }
+                }
+            };
+            visit(me.dependencies.as_ref(), false);
+            visit(
+                me.dev_dependencies
+                    .as_ref()
+                    .or_else(|| me.dev_dependencies2.as_ref()),
+                true,
+            );
+            visit(
+                me.build_dependencies
+                    .as_ref()
+                    .or_else(|| me.build_dependencies2.as_ref()),
+                false,
+            );
+            for platform in me.target.iter().flat_map(|t| t.values()) {
+                visit(platform.dependencies.as_ref(), false);
+                visit(
+                    platform
+                        .dev_dependencies
+                        .as_ref()
+                        .or_else(|| platform.dev_dependencies2.as_ref()),
+                    true,
+                );
+                visit(
+                    platform
+                        .build_dependencies
+                        .as_ref()
+                        .or_else(|| platform.build_dependencies2.as_ref()),
+                    false,
+                );
+            }
+            removed.retain(|name| !kept.contains(name));
+
+            let mut profiles = profiles.clone();
+            if removed.is_empty() {
+                return Ok(profiles);
+            }
+            for (profile_name, profile) in profiles.0.iter_mut() {
+                if let Some(overrides) = &mut profile.package {
+                    let mut pruned = Vec::new();
+                    overrides.retain(|spec, _| match spec {
+                        ProfilePackageSpec::Spec(spec)
+                            if removed.contains(spec.name().as_str()) =>
+                        {
+                            pruned.push(spec.to_string());
+                            false
+                        }
+                        _ => true,
+                    });
+                    for spec in pruned {
+                        config.shell().warn(format!(
+                            "profile package spec `{}` in profile `{}` refers to a \
+                             dev-dependency that is removed when publishing, \
+                             dropping it from the published manifest",
+                            spec, profile_name
+                        ))?;
+                    }
+                }
+            }
+            Ok(profiles)
+        }
+
         fn map_deps(
             config: &Config,
             deps: Option<&BTreeMap<String, TomlDependency>>,
@@ -1034,6 +1183,34 @@ impl TomlManifest {
         source_id: SourceId,
         package_root: &Path,
         config: &Config,
+    ) -> CargoResult<(Manifest, Vec<PathBuf>)> {
+        // Errors which don't prevent further analysis of the manifest are
+        // collected here, so that they can all be reported at once. They are
+        // also reported if a later error stops processing early, since they
+        // may well be its root cause.
+        let mut recoverable_errors = vec![];
+        match TomlManifest::do_to_real_manifest(
+            me,
+            source_id,
+            package_root,
+            config,
+            &mut recoverable_errors,
+        ) {
+            Ok(result) => Ok(result),
+            Err(e) => {
+                recoverable_errors.push(e);
+                // Never empty here, so this always produces an error.
+                Err(combine_errors(recoverable_errors).unwrap_err())
+            }
+        }
+    }
+
+    fn do_to_real_manifest(
+        me: &Rc<TomlManifest>,
+        source_id: SourceId,
+        package_root: &Path,
+        config: &Config,
+        recoverable_errors: &mut Vec<anyhow::Error>,
     ) -> CargoResult<(Manifest, Vec<PathBuf>)> {
         let mut nested_paths = vec![];
         let mut warnings = vec![];
@@ -1060,9 +1237,16 @@ impl TomlManifest {
             features
                 .require(Feature::edition())
                 .with_context(|| "editions are unstable")?;
-            edition
+            match edition
                 .parse()
-                .with_context(|| "failed to parse the `edition` key")?
+                .with_context(|| "failed to parse the `edition` key")
+            {
+                Ok(edition) => edition,
+                Err(e) => {
+                    recoverable_errors.push(e);
+                    Edition::Edition2015
+                }
+            }
         } else {
             Edition::Edition2015
         };
@@ -1198,36 +1382,64 @@ impl TomlManifest {
                 root: package_root,
             };
 
+            /// Processes a dependency table, collecting errors for individual
+            /// dependencies rather than stopping at the first one.
             fn process_dependencies(
                 cx: &mut Context<'_, '_>,
                 new_deps: Option<&BTreeMap<String, TomlDependency>>,
                 kind: Option<DepKind>,
-            ) -> CargoResult<()> {
+                errors: &mut Vec<anyhow::Error>,
+            ) {
                 let dependencies = match new_deps {
                     Some(dependencies) => dependencies,
-                    None => return Ok(()),
+                    None => return,
                 };
                 for (n, v) in dependencies.iter() {
-                    let dep = v.to_dependency(n, cx, kind)?;
-                    validate_package_name(dep.name_in_toml().as_str(), "dependency name", "")?;
-                    cx.deps.push(dep);
+                    // Only dependency tables written by the user are checked,
+                    // `[patch]` and `[replace]` entries often pin exact versions.
+                    if let Some(version) = v.version() {
+                        if version.contains('+') {
+                            cx.warnings.push(format!(
+                                "version requirement `{}` for dependency `{}` \
+                                 includes semver metadata which will be ignored, removing the \
+                                 metadata is recommended to avoid confusion",
+                                version, n
+                            ));
+                        }
+                    }
+                    let dep = v.to_dependency(n, cx, kind).and_then(|dep| {
+                        validate_package_name(dep.name_in_toml().as_str(), "dependency name", "")?;
+                        Ok(dep)
+                    });
+                    match dep {
+                        Ok(dep) => cx.deps.push(dep),
+                        Err(e) => errors.push(e),
+                    }
                 }
-
-                Ok(())
             }
 
             // Collect the dependencies.
-            process_dependencies(&mut cx, me.dependencies.as_ref(), None)?;
+            process_dependencies(&mut cx, me.dependencies.as_ref(), None, recoverable_errors);
             let dev_deps = me
                 .dev_dependencies
                 .as_ref()
                 .or_else(|| me.dev_dependencies2.as_ref());
-            process_dependencies(&mut cx, dev_deps, Some(DepKind::Development))?;
+            process_dependencies(
+                &mut cx,
+                dev_deps,
+                Some(DepKind::Development),
+                recoverable_errors,
+            );
             let build_deps = me
                 .build_dependencies
                 .as_ref()
                 .or_else(|| me.build_dependencies2.as_ref());
-            process_dependencies(&mut cx, build_deps, Some(DepKind::Build))?;
+            process_dependencies(
+                &mut cx,
+                build_deps,
+                Some(DepKind::Build),
+                recoverable_errors,
+            );
 
             for (name, platform) in me.target.iter().flatten() {
                 cx.platform = {
@@ -1235,17 +1447,32 @@ impl TomlManifest {
                     platform.check_cfg_attributes(&mut cx.warnings);
                     Some(platform)
                 };
-                process_dependencies(&mut cx, platform.dependencies.as_ref(), None)?;
+                process_dependencies(
+                    &mut cx,
+                    platform.dependencies.as_ref(),
+                    None,
+                    recoverable_errors,
+                );
                 let build_deps = platform
                     .build_dependencies
                     .as_ref()
                     .or_else(|| platform.build_dependencies2.as_ref());
-                process_dependencies(&mut cx, build_deps, Some(DepKind::Build))?;
+                process_dependencies(
+                    &mut cx,
+                    build_deps,
+                    Some(DepKind::Build),
+                    recoverable_errors,
+                );
                 let dev_deps = platform
                     .dev_dependencies
                     .as_ref()
                     .or_else(|| platform.dev_dependencies2.as_ref());
-                process_dependencies(&mut cx, dev_deps, Some(DepKind::Development))?;
+                process_dependencies(
+                    &mut cx,
+                    dev_deps,
+                    Some(DepKind::Development),
+                    recoverable_errors,
+                );
             }
 
             replace = me.replace(&mut cx)?;
@@ -1268,10 +1495,66 @@ impl TomlManifest {
             }
         }
 
+        let profiles = me.profile.clone();
+        if let Some(profiles) = &profiles {
+            if let Err(e) = profiles.validate(&features, &mut warnings) {
+                recoverable_errors.push(e);
+            }
+        }
+
+        if let Some(run) = &project.default_run {
+            if !targets
+                .iter()
+                .filter(|t| t.is_bin())
+                .any(|t| t.name() == run)
+            {
+                let suggestion =
+                    util::closest_msg(run, targets.iter().filter(|t| t.is_bin()), |t| t.name());
+                recoverable_errors.push(anyhow!(
+                    "default-run target `{}` not found{}",
+                    run,
+                    suggestion
+                ));
+            }
+        }
+
+        if let Some(example) = &project.default_example {
+            if !targets
+                .iter()
+                .filter(|t| t.is_example())
+                .any(|t| t.name() == example)
+            {
+                let suggestion =
+                    util::closest_msg(example, targets.iter().filter(|t| t.is_example()), |t| {
+                        t.name()
+                    });
+                recoverable_errors.push(anyhow!(
+                    "default-example target `{}` not found{}",
+                    example,
+                    suggestion
+                ));
+            }
+        }
+
+        // The rest of the manifest can't be processed reliably with invalid
+        // dependencies, so report everything collected so far.
+        combine_errors(std::mem::take(recoverable_errors))?;
+
         let exclude = project.exclude.clone().unwrap_or_default();
         let include = project.include.clone().unwrap_or_default();
         let empty_features = BTreeMap::new();
 
+        if let Some(keywords) = &project.keywords {
+            validate_crates_io_list(keywords, "keywords", &mut warnings);
+            validate_keyword_lengths(keywords, &mut warnings);
+        }
+        if let Some(categories) = &project.categories {
+            validate_crates_io_list(categories, "categories", &mut warnings);
+        }
+        if let Some(license) = &project.license {
+            warn_deprecated_license_ids(license, &mut warnings);
+        }
+
         let summary = Summary::new(
             config,
             pkgid,
@@ -1313,10 +1596,6 @@ impl TomlManifest {
                  `[workspace]`, only one can be specified"
             ),
         };
-        let profiles = me.profile.clone();
-        if let Some(profiles) = &profiles {
-            profiles.validate(&features, &mut warnings)?;
-        }
         let publish = match project.publish {
             Some(VecStringOrBool::VecString(ref vecstring)) => Some(vecstring.clone()),
             Some(VecStringOrBool::Bool(false)) => Some(vec![]),
@@ -1331,18 +1610,6 @@ impl TomlManifest {
             )
         }
 
-        if let Some(run) = &project.default_run {
-            if !targets
-                .iter()
-                .filter(|t| t.is_bin())
-                .any(|t| t.name() == run)
-            {
-                let suggestion =
-                    util::closest_msg(run, targets.iter().filter(|t| t.is_bin()), |t| t.name());
-                bail!("default-run target `{}` not found{}", run, suggestion);
-            }
-        }
-
         let default_kind = project
             .default_target
             .as_ref()
@@ -1377,6 +1644,7 @@ impl TomlManifest {
             rust_version,
             project.im_a_teapot,
             project.default_run.clone(),
+            project.default_example.clone(),
             Rc::clone(me),
             project.metabuild.clone().map(|sov| sov.0),
             resolve_behavior,
@@ -1388,6 +1656,22 @@ impl TomlManifest {
                     .to_string(),
             );
         }
+        if let Some(license_file) = &project.license_file {
+            // Only check path sources, other sources are validated when they
+            // are packaged.
+            let license_path = Path::new(license_file);
+            if source_id.is_path() && !package_root.join(license_path).exists() {
+                let rel_msg = if license_path.is_absolute() {
+                    "".to_string()
+                } else {
+                    format!(" (relative to `{}`)", package_root.display())
+                };
+                manifest.warnings_mut().add_warning(format!(
+                    "license-file `{}` does not appear to exist{}",
+                    license_file, rel_msg
+                ));
+            }
+        }
         for warning in warnings {
             manifest.warnings_mut().add_warning(warning);
         }
@@ -1611,16 +1895,23 @@ fn readme_for_project(package_root: &Path, project: &TomlProject) -> Option<Stri
         None => default_readme_from_package_root(package_root),
         Some(value) => match value {
             StringOrBool::Bool(false) => None,
-            StringOrBool::Bool(true) => Some("README.md".to_string()),
+            StringOrBool::Bool(true) => Some(
+                default_readme_from_package_root(package_root)
+                    .or_else(|| default_readme_from_docs_dir(package_root))
+                    .unwrap_or_else(|| "README.md".to_string()),
+            ),
             StringOrBool::String(v) => Some(v.clone()),
         },
     }
 }
 
-const DEFAULT_README_FILES: [&str; 3] = ["README.md", "README.txt", "README"];
+const DEFAULT_README_FILES: [&str; 4] = ["README.md", "README.txt", "README", "README.markdown"];
 
 /// Checks if a file with any of the default README file names exists in the package root.
 /// If so, returns a `String` representing that name.
+///
+/// Exact names are preferred, otherwise the names are matched case-insensitively
+/// so that files like `readme.md` or `Readme.md` are also found.
 fn default_readme_from_package_root(package_root: &Path) -> Option<String> {
     for &readme_filename in DEFAULT_README_FILES.iter() {
         if package_root.join(readme_filename).is_file() {
@@ -1628,7 +1919,123 @@ fn default_readme_from_package_root(package_root: &Path) -> Option<String> {
         }
     }
 
-    None
+    let mut file_names: Vec<String> = fs::read_dir(package_root)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    file_names.sort();
+    DEFAULT_README_FILES.iter().find_map(|readme_filename| {
+        file_names
+            .iter()
+            .find(|name| name.eq_ignore_ascii_case(readme_filename))
+            .cloned()
+    })
+}
+
+/// Checks the `docs` directory of the package for a default README file.
+/// If so, returns its path relative to the package root.
+fn default_readme_from_docs_dir(package_root: &Path) -> Option<String> {
+    default_readme_from_package_root(&package_root.join("docs"))
+        .map(|readme_filename| format!("docs/{}", readme_filename))
+}
+
+/// Turns a list of errors found while processing a manifest into a single
+/// error. A lone error is returned unchanged.
+fn combine_errors(mut errors: Vec<anyhow::Error>) -> CargoResult<()> {
+    match errors.len() {
+        0 => Ok(()),
+        1 => Err(errors.pop().unwrap()),
+        n => {
+            let mut msg = format!("{} errors were found in the manifest:", n);
+            for error in errors {
+                msg.push_str(&format!("\n\n{:#}", error));
+            }
+            Err(anyhow::format_err!(msg))
+        }
+    }
+}
+
+/// The maximum number of keywords or categories accepted by crates.io.
+const MAX_CRATES_IO_LIST_LEN: usize = 5;
+/// The maximum length of a single keyword accepted by crates.io.
+const MAX_KEYWORD_LEN: usize = 20;
+
+/// Warns about `keywords`/`categories` lists that are longer than crates.io
+/// accepts, so they can be fixed before `cargo publish` fails.
+fn validate_crates_io_list(list: &[String], whats: &str, warnings: &mut Vec<String>) {
+    if list.len() > MAX_CRATES_IO_LIST_LEN {
+        warnings.push(format!(
+            "crates.io accepts at most {} {}, but {} were specified; \
+             `{}` will be rejected when publishing",
+            MAX_CRATES_IO_LIST_LEN,
+            whats,
+            list.len(),
+            list[MAX_CRATES_IO_LIST_LEN..].join("`, `"),
+        ));
+    }
+}
+
+/// Warns about keywords that are too long for crates.io.
+///
+/// Categories are not checked, since they are fixed slugs chosen by
+/// crates.io and many of them are longer than a keyword may be.
+fn validate_keyword_lengths(keywords: &[String], warnings: &mut Vec<String>) {
+    for keyword in keywords {
+        if keyword.chars().count() > MAX_KEYWORD_LEN {
+            warnings.push(format!(
+                "keyword `{}` is longer than {} characters and will be rejected \
+                 by crates.io when publishing",
+                keyword, MAX_KEYWORD_LEN,
+            ));
+        }
+    }
+}
+
+/// SPDX license identifiers which are deprecated, along with the identifier
+/// that replaces them.
+const DEPRECATED_LICENSE_IDS: &[(&str, &str)] = &[
+    ("AGPL-1.0", "AGPL-1.0-only"),
+    ("AGPL-3.0", "AGPL-3.0-only"),
+    ("BSD-2-Clause-FreeBSD", "BSD-2-Clause"),
+    ("BSD-2-Clause-NetBSD", "BSD-2-Clause"),
+    ("GFDL-1.1", "GFDL-1.1-only"),
+    ("GFDL-1.2", "GFDL-1.2-only"),
+    ("GFDL-1.3", "GFDL-1.3-only"),
+    ("GPL-1.0", "GPL-1.0-only"),
+    ("GPL-1.0+", "GPL-1.0-or-later"),
+    ("GPL-2.0", "GPL-2.0-only"),
+    ("GPL-2.0+", "GPL-2.0-or-later"),
+    ("GPL-3.0", "GPL-3.0-only"),
+    ("GPL-3.0+", "GPL-3.0-or-later"),
+    ("LGPL-2.0", "LGPL-2.0-only"),
+    ("LGPL-2.0+", "LGPL-2.0-or-later"),
+    ("LGPL-2.1", "LGPL-2.1-only"),
+    ("LGPL-2.1+", "LGPL-2.1-or-later"),
+    ("LGPL-3.0", "LGPL-3.0-only"),
+    ("LGPL-3.0+", "LGPL-3.0-or-later"),
+    ("StandardML-NJ", "SMLNJ"),
+];
+
+/// Warns about deprecated SPDX identifiers used in a `license` expression,
+/// suggesting the identifier which replaces each of them.
+fn warn_deprecated_license_ids(license: &str, warnings: &mut Vec<String>) {
+    let ids = license
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ')' || c == '/')
+        .filter(|id| !id.is_empty());
+    for id in ids {
+        if let Some((deprecated, replacement)) = DEPRECATED_LICENSE_IDS
+            .iter()
+            .find(|(deprecated, _)| deprecated.eq_ignore_ascii_case(id))
+        {
+            warnings.push(format!(
+                "the `license` field uses the deprecated SPDX identifier `{}`, \
+                 consider using `{}` instead",
+                deprecated, replacement
+            ));
+        }
+    }
 }
 
 /// Checks a list of build targets, and ensures the target names are unique within a vector.
@@ -1691,6 +2098,13 @@ impl<P: ResolveToPath> TomlDependency<P> {
         }
     }
 
+    fn version(&self) -> Option<&str> {
+        match self {
+            TomlDependency::Detailed(d) => d.version.as_deref(),
+            TomlDependency::Simple(version) => Some(version),
+        }
+    }
+
     fn is_version_specified(&self) -> bool {
         match self {
             TomlDependency::Detailed(d) => d.version.is_some(),
@@ -1717,17 +2131,6 @@ impl<P: ResolveToPath> DetailedTomlDependency<P> {
             cx.warnings.push(msg);
         }
 
-        if let Some(version) = &self.version {
-            if version.contains('+') {
-                cx.warnings.push(format!(
-                    "version requirement `{}` for dependency `{}` \
-                     includes semver metadata which will be ignored, removing the \
-                     metadata is recommended to avoid confusion",
-                    version, name_in_toml
-                ));
-            }
-        }
-
         if self.git.is_none() {
             let git_only_keys = [
                 (&self.branch, "branch"),
@@ -10,7 +10,7 @@
 //! It is a bit tricky because we need match explicit information from `Cargo.toml`
 //! with implicit info in directory layout.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, DirEntry};
 use std::path::{Path, PathBuf};
 
@@ -652,18 +652,24 @@ fn toml_targets_and_inferred(
             let target_path =
                 |target: &TomlTarget| target.path.clone().map(|p| package_root.join(p.0));
 
-            let mut seen_names = HashSet::new();
-            let mut seen_paths = HashSet::new();
-            for target in targets.iter() {
-                seen_names.insert(target.name.clone());
-                seen_paths.insert(target_path(target));
-            }
+            let seen_paths: HashSet<_> = targets.iter().map(target_path).collect();
 
             let mut rem_targets = vec![];
+            // Inferred files whose name is taken by an explicit target with
+            // a different path. These are never built.
+            let mut superseded = vec![];
             for target in inferred_targets {
-                if !seen_names.contains(&target.name) && !seen_paths.contains(&target_path(&target))
-                {
-                    rem_targets.push(target);
+                let path = target_path(&target);
+                if seen_paths.contains(&path) {
+                    continue;
+                }
+                match targets.iter().find(|t| t.name == target.name) {
+                    None => rem_targets.push(target),
+                    Some(explicit) => {
+                        if let Some(explicit_path) = &explicit.path {
+                            superseded.push((target, explicit_path.0.clone()));
+                        }
+                    }
                 }
             }
 
@@ -708,6 +714,20 @@ https://github.com/rust-lang/cargo/issues/5330",
             };
 
             if autodiscover {
+                for (target, explicit_path) in superseded {
+                    let inferred_path = target.path.unwrap().0;
+                    warnings.push(format!(
+                        "{} target `{}` is set to `{}` in Cargo.toml, \
+                         so the automatically discovered `{}` is not built",
+                        target_kind_human,
+                        target.name.unwrap(),
+                        explicit_path.display(),
+                        inferred_path
+                            .strip_prefix(package_root)
+                            .unwrap_or(&inferred_path)
+                            .display(),
+                    ));
+                }
                 targets.append(&mut rem_targets);
             }
 
@@ -758,15 +778,24 @@ fn validate_target_name(
 
 /// Will check a list of toml targets, and make sure the target names are unique within a vector.
 fn validate_unique_names(targets: &[TomlTarget], target_kind: &str) -> CargoResult<()> {
-    let mut seen = HashSet::new();
-    for name in targets.iter().map(|e| e.name()) {
-        if !seen.insert(name.clone()) {
-            anyhow::bail!(
+    let mut seen = HashMap::new();
+    for target in targets {
+        let name = target.name();
+        if let Some(prev_path) = seen.insert(name.clone(), target.path.as_ref()) {
+            let mut msg = format!(
                 "found duplicate {target_kind} name {name}, \
                  but all {target_kind} targets must have a unique name",
                 target_kind = target_kind,
                 name = name
             );
+            if let (Some(prev_path), Some(path)) = (prev_path, target.path.as_ref()) {
+                msg.push_str(&format!(
+                    "\nthe conflicting targets are defined at `{}` and `{}`",
+                    prev_path.0.display(),
+                    path.0.display()
+                ));
+            }
+            anyhow::bail!(msg);
         }
     }
     Ok(())
@@ -480,6 +480,7 @@ fn duplicate_binary_names() {
 
 Caused by:
   found duplicate binary name e, but all binary targets must have a unique name
+  the conflicting targets are defined at `a.rs` and `b.rs`
 ",
         )
         .run();
@@ -517,6 +518,7 @@ fn duplicate_example_names() {
 
 Caused by:
   found duplicate example name ex, but all example targets must have a unique name
+  the conflicting targets are defined at `examples/ex.rs` and `examples/ex2.rs`
 ",
         )
         .run();
@@ -554,6 +556,7 @@ fn duplicate_bench_names() {
 
 Caused by:
   found duplicate bench name ex, but all bench targets must have a unique name
+  the conflicting targets are defined at `benches/ex.rs` and `benches/ex2.rs`
 ",
         )
         .run();
@@ -1366,6 +1369,178 @@ fn warn_semver_metadata() {
         .run();
 }
 
+#[cargo_test]
+fn no_warn_semver_metadata_in_patch() {
+    Package::new("bar", "1.0.0").publish();
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+            [package]
+            name = "foo"
+            version = "1.0.0"
+
+            [dependencies]
+            bar = "1.0.0"
+
+            [patch.crates-io]
+            bar = { path = "bar", version = "1.0.0+1234" }
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .file("bar/Cargo.toml", &basic_manifest("bar", "1.0.0+1234"))
+        .file("bar/src/lib.rs", "")
+        .build();
+    p.cargo("check")
+        .with_stderr_does_not_contain("[..]semver metadata[..]")
+        .run();
+}
+
+#[cargo_test]
+fn multiple_manifest_errors() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+            [package]
+            name = "foo"
+            version = "1.0.0"
+            edition = "2000"
+
+            [dependencies]
+            bar = { git = "https://example.com/bar", registry = "alternative" }
+
+            [profile.dev]
+            panic = "crash"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+    p.cargo("check")
+        .with_status(101)
+        .with_stderr(
+            "\
+[ERROR] failed to parse manifest at `[..]`
+
+Caused by:
+  3 errors were found in the manifest:
+
+  failed to parse the `edition` key: supported edition values are `2015`, `2018`, \
+or `2021`, but `2000` is unknown
+
+  dependency (bar) specification is ambiguous. Only one of `git` or `registry` is allowed.
+
+  `panic` setting of `crash` is not a valid setting, must be `unwind` or `abort`
+",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn manifest_errors_kept_on_later_failure() {
+    // An error that stops processing early is reported along with the
+    // errors collected before it.
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+            [package]
+            name = "foo"
+            version = "1.0.0"
+            edition = "2000"
+
+            [[bin]]
+            name = "missing"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+    p.cargo("check")
+        .with_status(101)
+        .with_stderr(
+            "\
+[ERROR] failed to parse manifest at `[..]`
+
+Caused by:
+  2 errors were found in the manifest:
+
+  failed to parse the `edition` key: supported edition values are `2015`, `2018`, \
+or `2021`, but `2000` is unknown
+
+  can't find `missing` bin, specify bin.path
+",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn warn_crates_io_keyword_and_category_limits() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+            [package]
+            name = "foo"
+            version = "1.0.0"
+            keywords = ["a", "b", "c", "d", "e", "command-line-utilities"]
+            categories = ["command-line-utilities", "development-tools::cargo-plugins"]
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+    p.cargo("check")
+        .with_stderr(
+            "\
+[WARNING] crates.io accepts at most 5 keywords, but 6 were specified; \
+`command-line-utilities` will be rejected when publishing
+[WARNING] keyword `command-line-utilities` is longer than 20 characters \
+and will be rejected by crates.io when publishing
+[CHECKING] foo v1.0.0 ([CWD])
+[FINISHED] [..]
+",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn warn_deprecated_license_identifier() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+            [package]
+            name = "foo"
+            version = "1.0.0"
+            license = "MIT OR GPL-3.0"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+    p.cargo("check")
+        .with_stderr(
+            "\
+[WARNING] the `license` field uses the deprecated SPDX identifier `GPL-3.0`, \
+consider using `GPL-3.0-only` instead
+[CHECKING] foo v1.0.0 ([CWD])
+[FINISHED] [..]
+",
+        )
+        .run();
+
+    p.change_file(
+        "Cargo.toml",
+        r#"
+        [package]
+        name = "foo"
+        version = "1.0.0"
+        license = "MIT OR GPL-3.0-only"
+        "#,
+    );
+    p.cargo("check")
+        .with_stderr_does_not_contain("[..]deprecated[..]")
+        .run();
+}
+
 #[cargo_test]
 fn bad_target_cfg() {
     // Invalid type in a StringList.
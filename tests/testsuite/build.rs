@@ -4372,6 +4372,40 @@ fn inferred_bin_path() {
     assert!(p.bin("bar").is_file());
 }
 
+#[cargo_test]
+fn inferred_bin_superseded_by_explicit_path() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+            [package]
+            name = "foo"
+            version = "0.1.0"
+            authors = []
+            edition = "2018"
+
+            [[bin]]
+            name = "bar"
+            path = "src/bar_main.rs"
+            "#,
+        )
+        .file("src/bar_main.rs", "fn main() {}")
+        .file("src/bin/bar.rs", "fn main() {}")
+        .build();
+
+    p.cargo("build")
+        .with_stderr(
+            "\
+[WARNING] binary target `bar` is set to `src/bar_main.rs` in Cargo.toml, \
+so the automatically discovered `src/bin/bar.rs` is not built
+[COMPILING] foo v0.1.0 ([CWD])
+[FINISHED] [..]
+",
+        )
+        .run();
+    assert!(p.bin("bar").is_file());
+}
+
 #[cargo_test]
 fn inferred_examples() {
     let p = project()
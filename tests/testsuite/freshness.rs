@@ -2659,3 +2659,42 @@ fn changing_linker() {
         )
         .run();
 }
+
+#[cargo_test]
+fn rebuild_on_changed_profile_use_data() {
+    // Changing the contents of the `-C profile-use` file triggers a rebuild,
+    // even though the path in the flags stays the same.
+    let p = project()
+        .file("src/lib.rs", "")
+        .file("merged.profdata", "first")
+        .build();
+    let rustflags = format!(
+        "-Cprofile-use={}",
+        p.root().join("merged.profdata").display()
+    );
+
+    p.cargo("check")
+        .env("RUSTFLAGS", &rustflags)
+        .with_stderr(
+            "\
+[CHECKING] foo v0.0.1 ([..])
+[FINISHED] [..]
+",
+        )
+        .run();
+    p.cargo("check")
+        .env("RUSTFLAGS", &rustflags)
+        .with_stderr("[FINISHED] [..]")
+        .run();
+
+    p.change_file("merged.profdata", "second");
+    p.cargo("check")
+        .env("RUSTFLAGS", &rustflags)
+        .with_stderr(
+            "\
+[CHECKING] foo v0.0.1 ([..])
+[FINISHED] [..]
+",
+        )
+        .run();
+}
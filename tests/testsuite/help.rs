@@ -43,6 +43,41 @@ fn help_external_subcommand() {
         .run();
 }
 
+#[cargo_test]
+fn help_nested_external_subcommand() {
+    // Check that `help external-subcommand nested` forwards the nested
+    // subcommand along with the --help flag.
+    Package::new("cargo-fake-help", "1.0.0")
+        .file(
+            "src/main.rs",
+            r#"
+            fn main() {
+                let args: Vec<_> = ::std::env::args().skip(1).collect();
+                println!("{}", args.join(" "));
+            }
+            "#,
+        )
+        .publish();
+    cargo_process("install cargo-fake-help").run();
+    cargo_process("help fake-help nested")
+        .with_stdout("fake-help nested --help\n")
+        .run();
+}
+
+#[cfg(unix)]
+#[cargo_test]
+fn help_external_subcommand_non_utf8_arg() {
+    // Non-UTF-8 arguments after an unknown subcommand shouldn't panic.
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    cargo_process("help nosuchcmd nested")
+        .arg(OsStr::from_bytes(b"\xff"))
+        .with_status(101)
+        .with_stderr_contains("error: no such subcommand: `nosuchcmd`")
+        .run();
+}
+
 #[cargo_test]
 fn z_flags_help() {
     // Test that the output of `cargo -Z help` shows a different help screen with
@@ -121,6 +156,144 @@ fn help_man() {
     help_with_man_and_path("", "build", "build", Path::new(""));
 }
 
+#[cargo_test]
+fn help_cargo_pager() {
+    // Build a pager that records its arguments and echoes the contents.
+    let p = project()
+        .at("my-pager")
+        .file("Cargo.toml", &basic_manifest("my-pager", "1.0.0"))
+        .file(
+            "src/main.rs",
+            r#"
+                fn main() {
+                    let args: Vec<_> = std::env::args().skip(1).collect();
+                    eprintln!("custom pager {}", args[..args.len() - 1].join(" "));
+                    let mut f = std::fs::File::open(args.last().unwrap()).unwrap();
+                    std::io::copy(&mut f, &mut std::io::stdout()).unwrap();
+                }
+            "#,
+        )
+        .build();
+    p.cargo("build").run();
+    let pager = p.bin("my-pager");
+    let contents = fs::read_to_string("src/doc/man/generated_txt/cargo-build.txt").unwrap();
+
+    // `CARGO_PAGER` is used even if `man` is available.
+    help_with_man("man");
+    let man_dir = paths::root().join("man/target/debug");
+    for (key, value, expected_stderr) in [
+        (
+            "CARGO_PAGER",
+            format!("{} -R", pager.display()),
+            "custom pager -R\n",
+        ),
+        ("CARGO_PAGER", String::new(), ""),
+    ] {
+        let output = process(&cargo_exe())
+            .arg("help")
+            .arg("build")
+            .env("PATH", &man_dir)
+            .env(key, value)
+            .exec_with_output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(from_utf8(&output.stderr).unwrap(), expected_stderr);
+        assert_eq!(from_utf8(&output.stdout).unwrap(), contents);
+    }
+
+    // `PAGER` only replaces the `less`/`more` fallback.
+    let output = process(&cargo_exe())
+        .arg("help")
+        .arg("build")
+        .env("PATH", "")
+        .env("PAGER", pager.display().to_string())
+        .exec_with_output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(from_utf8(&output.stderr).unwrap(), "custom pager \n");
+    assert_eq!(from_utf8(&output.stdout).unwrap(), contents);
+}
+
+#[cargo_test]
+fn help_text() {
+    // `--text` prints the text man page even if `man` is available.
+    help_with_man("man");
+    let man_dir = paths::root().join("man/target/debug");
+    let contents = fs::read_to_string("src/doc/man/generated_txt/cargo-build.txt").unwrap();
+    for args in [&["help", "build", "--text"], &["help", "--text", "build"]] {
+        let output = process(&cargo_exe())
+            .args(args)
+            .env("PATH", &man_dir)
+            .exec_with_output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(from_utf8(&output.stderr).unwrap(), "");
+        assert_eq!(from_utf8(&output.stdout).unwrap(), contents);
+    }
+}
+
+#[cargo_test]
+fn help_text_requires_command() {
+    cargo_process("help --text")
+        .with_status(1)
+        .with_stderr_contains(
+            "\
+error: The following required arguments were not provided:
+    <COMMAND>...",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn help_man_path() {
+    let output = cargo_process("help build --man-path")
+        .exec_with_output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = from_utf8(&output.stdout).unwrap();
+    let path = Path::new(stdout.trim_end());
+    let contents = fs::read_to_string(path).unwrap();
+    fs::remove_file(path).unwrap();
+    assert_eq!(
+        contents,
+        fs::read_to_string("src/etc/man/cargo-build.1").unwrap()
+    );
+}
+
+#[cargo_test]
+fn help_man_path_requires_command() {
+    cargo_process("help --man-path")
+        .with_status(1)
+        .with_stderr_contains(
+            "\
+error: The following required arguments were not provided:
+    <COMMAND>...",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn help_list_json() {
+    cargo_process("help --list-json")
+        .with_stdout_contains(
+            "[..]{\"name\":\"build\",\
+             \"about\":\"Compile a local package and all of its dependencies\",\
+             \"aliases\":[\"b\"],\"hidden\":false}[..]",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn help_list_json_with_command() {
+    cargo_process("help build --list-json")
+        .with_status(1)
+        .with_stdout("")
+        .with_stderr_contains(
+            "error: The argument '<COMMAND>...' cannot be used with '--list-json'",
+        )
+        .run();
+}
+
 #[cargo_test]
 fn help_alias() {
     // Check that `help some_alias` will resolve.
@@ -136,5 +309,19 @@ fn help_alias() {
         "#,
     )
     .unwrap();
-    help_with_man_and_path("", "my-alias", "build", Path::new(""));
+    let output = process(&cargo_exe())
+        .arg("help")
+        .arg("my-alias")
+        .env("PATH", "")
+        .exec_with_output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        from_utf8(&output.stderr).unwrap(),
+        "note: `my-alias` is aliased to `build --release`, showing help for `build`\n"
+    );
+    assert_eq!(
+        from_utf8(&output.stdout).unwrap(),
+        fs::read_to_string("src/doc/man/generated_txt/cargo-build.txt").unwrap()
+    );
 }
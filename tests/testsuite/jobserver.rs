@@ -4,7 +4,7 @@ use std::net::TcpListener;
 use std::process::Command;
 use std::thread;
 
-use cargo_test_support::{cargo_exe, project};
+use cargo_test_support::{basic_manifest, cargo_exe, project};
 
 #[cargo_test]
 fn jobserver_exists() {
@@ -204,3 +204,68 @@ with an external jobserver in its environment, ignoring the `-j` parameter
         )
         .run();
 }
+
+#[cargo_test]
+#[cfg(unix)]
+fn invalid_jobserver_auth() {
+    // File descriptors that aren't open in cargo's process, like when a
+    // parent `make` doesn't mark the recipe with `+`.
+    let p = project().file("src/lib.rs", "").build();
+
+    p.cargo("build")
+        .env("CARGO_MAKEFLAGS", "--jobserver-auth=1000,1001")
+        .with_stderr(
+            "\
+[WARNING] failed to connect to the jobserver from environment variable \
+`CARGO_MAKEFLAGS=\"--jobserver-auth=1000,1001\"`: [..]
+falling back to the parallelism of `-j`
+[COMPILING] foo [..]
+[FINISHED] [..]
+",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn jobserver_token_logging() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                [package]
+                name = "foo"
+                version = "0.0.1"
+
+                [dependencies]
+                a = { path = "a" }
+                b = { path = "b" }
+                c = { path = "c" }
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .file("a/Cargo.toml", &basic_manifest("a", "0.0.1"))
+        .file("a/src/lib.rs", "")
+        .file("b/Cargo.toml", &basic_manifest("b", "0.0.1"))
+        .file("b/src/lib.rs", "")
+        .file("c/Cargo.toml", &basic_manifest("c", "0.0.1"))
+        .file("c/src/lib.rs", "")
+        .build();
+
+    let output = p
+        .cargo("build -j2")
+        .env("CARGO_LOG", "cargo::core::compiler::job_queue=info")
+        .exec_with_output()
+        .unwrap();
+    let stderr = std::str::from_utf8(&output.stderr).unwrap();
+    let held: Vec<usize> = stderr
+        .lines()
+        .filter(|line| line.contains("jobserver token acquired"))
+        .map(|line| {
+            let count = line.rsplit(", ").next().unwrap();
+            count.split(' ').next().unwrap().parse().unwrap()
+        })
+        .collect();
+    assert!(!held.is_empty(), "no tokens acquired:\n{}", stderr);
+    // The implicit token plus the extra ones never exceed `-j`.
+    assert!(held.iter().all(|&n| n < 2), "{}", stderr);
+}
@@ -1037,6 +1037,73 @@ authors = []
     );
 }
 
+#[cargo_test]
+fn prune_profile_overrides_for_removed_dev_deps() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                [project]
+                name = "foo"
+                version = "0.0.1"
+                authors = []
+                license = "MIT"
+                description = "foo"
+
+                [dependencies]
+                baz = { path = "baz", version = "0.1" }
+
+                [dev-dependencies]
+                bar = { path = "bar" }
+
+                [profile.dev.package.bar]
+                opt-level = 1
+
+                [profile.dev.package.baz]
+                opt-level = 2
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .file("bar/Cargo.toml", &basic_manifest("bar", "0.1.0"))
+        .file("bar/src/lib.rs", "")
+        .file("baz/Cargo.toml", &basic_manifest("baz", "0.1.0"))
+        .file("baz/src/lib.rs", "")
+        .build();
+
+    p.cargo("package --no-verify")
+        .with_stderr_contains(
+            "[WARNING] profile package spec `bar` in profile `dev` refers to a \
+             dev-dependency that is removed when publishing, \
+             dropping it from the published manifest",
+        )
+        .run();
+
+    let f = File::open(&p.root().join("target/package/foo-0.0.1.crate")).unwrap();
+    let rewritten_toml = format!(
+        r#"{}
+[package]
+name = "foo"
+version = "0.0.1"
+authors = []
+description = "foo"
+license = "MIT"
+[profile.dev.package.baz]
+opt-level = 2
+[dependencies.baz]
+version = "0.1"
+
+[dev-dependencies]
+"#,
+        cargo::core::package::MANIFEST_PREAMBLE
+    );
+    validate_crate_contents(
+        f,
+        "foo-0.0.1.crate",
+        &["Cargo.toml", "Cargo.toml.orig", "src/lib.rs"],
+        &[("Cargo.toml", &rewritten_toml)],
+    );
+}
+
 #[cargo_test]
 fn package_two_kinds_of_deps() {
     Package::new("other", "1.0.0").publish();
@@ -1175,6 +1242,75 @@ Caused by:
         .run();
 }
 
+#[cargo_test]
+fn test_edition_typo() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"[package]
+                edition = "2022"
+                name = "foo"
+                version = "99.99.99"
+                authors = []
+            "#,
+        )
+        .file("src/main.rs", r#""#)
+        .build();
+
+    p.cargo("build")
+        .with_status(101)
+        .with_stderr(
+            "\
+error: failed to parse manifest at `[..]`
+
+Caused by:
+  failed to parse the `edition` key
+
+Caused by:
+  this version of Cargo is older than the `2022` edition, and only supports `2015`, `2018`, and `2021` editions.
+
+  <tab>Did you mean `2021`? It is unstable and requires `cargo-features = [\"edition2021\"]`
+"
+            .to_string(),
+        )
+        .run();
+}
+
+#[cargo_test]
+fn test_edition_typo_nearest_year() {
+    // `2019` is one character away from both `2015` and `2018`.
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"[package]
+                edition = "2019"
+                name = "foo"
+                version = "99.99.99"
+                authors = []
+            "#,
+        )
+        .file("src/main.rs", r#""#)
+        .build();
+
+    p.cargo("build")
+        .with_status(101)
+        .with_stderr(
+            "\
+error: failed to parse manifest at `[..]`
+
+Caused by:
+  failed to parse the `edition` key
+
+Caused by:
+  supported edition values are `2015`, `2018`, or `2021`, but `2019` is unknown
+
+  <tab>Did you mean `2018`?
+"
+            .to_string(),
+        )
+        .run();
+}
+
 #[cargo_test]
 fn do_not_package_if_src_was_modified() {
     let p = project()
@@ -1524,6 +1660,41 @@ fn gitignore_negate() {
          foo.rs\n\
          ",
     );
+
+    // Like `include`, a negated `exclude` rule can re-include a file inside
+    // an excluded directory.
+    include_exclude_test(
+        "[]",
+        r#"["assets/", "!assets/keep.txt"]"#, // exclude
+        &["src/lib.rs", "assets/keep.txt", "assets/tmp/big.bin"],
+        "Cargo.toml\n\
+         Cargo.toml.orig\n\
+         assets/keep.txt\n\
+         src/lib.rs\n\
+         ",
+    );
+
+    // Rules are applied in order, so a negation only undoes earlier rules.
+    include_exclude_test(
+        "[]",
+        r#"["!keep.txt", "*.txt"]"#, // exclude
+        &["src/lib.rs", "keep.txt"],
+        "Cargo.toml\n\
+         Cargo.toml.orig\n\
+         src/lib.rs\n\
+         ",
+    );
+
+    // A negation without a matching earlier rule has no effect.
+    include_exclude_test(
+        "[]",
+        r#"["!src/lib.rs"]"#, // exclude
+        &["src/lib.rs"],
+        "Cargo.toml\n\
+         Cargo.toml.orig\n\
+         src/lib.rs\n\
+         ",
+    );
 }
 
 #[cargo_test]
@@ -1581,6 +1752,33 @@ This may become a hard error in the future.
         .run();
 }
 
+#[cargo_test]
+fn invalid_license_file_path_warns_on_build() {
+    // A missing license-file is reported as soon as the manifest is loaded.
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+            [package]
+            name = "foo"
+            version = "1.0.0"
+            license-file = "does-not-exist"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+
+    p.cargo("check")
+        .with_stderr(
+            "\
+[WARNING] license-file `does-not-exist` does not appear to exist (relative to `[..]/foo`)
+[CHECKING] foo v1.0.0 ([..]/foo)
+[FINISHED] [..]
+",
+        )
+        .run();
+}
+
 #[cargo_test]
 fn license_file_implicit_include() {
     // license-file should be automatically included even if not listed.
@@ -230,10 +230,29 @@ fn from_config_precedence() {
         .masquerade_as_nightly_cargo()
         .with_stderr(
             "\
+[WARNING] [patch] for `bar` in cargo config is ignored, because the [patch] for it \
+in `[CWD]/Cargo.toml` takes precedence
+manifest: [CWD]/bar
+config:   [CWD]/no-such-path
 [UPDATING] `[ROOT][..]` index
 [COMPILING] bar v0.1.1 ([..])
 [COMPILING] foo v0.0.1 ([CWD])
 [FINISHED] dev [unoptimized + debuginfo] target(s) in [..]
+",
+        )
+        .run();
+
+    // With a lock file the patches are loaded more than once, but the
+    // warning should still only be printed once.
+    p.cargo("build -Zpatch-in-config")
+        .masquerade_as_nightly_cargo()
+        .with_stderr(
+            "\
+[WARNING] [patch] for `bar` in cargo config is ignored, because the [patch] for it \
+in `[CWD]/Cargo.toml` takes precedence
+manifest: [CWD]/bar
+config:   [CWD]/no-such-path
+[FINISHED] dev [unoptimized + debuginfo] target(s) in [..]
 ",
         )
         .run();
@@ -576,6 +595,43 @@ fn unused_git() {
         .run();
 }
 
+#[cargo_test]
+fn unused_not_depended_on() {
+    // A patch for a package that nothing depends on says so.
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                [package]
+                name = "foo"
+                version = "0.0.1"
+                authors = []
+
+                [patch.crates-io]
+                baz = { path = "baz" }
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .file("baz/Cargo.toml", &basic_manifest("baz", "0.1.0"))
+        .file("baz/src/lib.rs", "")
+        .build();
+
+    p.cargo("build")
+        .with_stderr(
+            "\
+[WARNING] Patch `baz v0.1.0 ([CWD]/baz)` was not used in the crate graph, \
+no package in the graph is named `baz`.
+[..]
+[..]
+[..]
+[..]
+[COMPILING] foo v0.0.1 ([CWD])
+[FINISHED] dev [unoptimized + debuginfo] target(s) in [..]
+",
+        )
+        .run();
+}
+
 #[cargo_test]
 fn add_patch() {
     Package::new("bar", "0.1.0").publish();
@@ -336,6 +336,44 @@ fn profile_config_mixed_types() {
         .run();
 }
 
+#[cargo_test]
+fn manifest_profile_inherits_config_profile() {
+    // A manifest profile may inherit from a profile only defined in config.
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                cargo-features = ["named-profiles"]
+
+                [package]
+                name = "foo"
+                version = "0.0.1"
+
+                [profile.fast]
+                inherits = "mine"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .file(
+            ".cargo/config.toml",
+            r#"
+                [profile.mine]
+                inherits = "release"
+            "#,
+        )
+        .build();
+
+    p.cargo("build -Z unstable-options --profile fast")
+        .masquerade_as_nightly_cargo()
+        .with_stderr(
+            "\
+[COMPILING] foo [..]
+[FINISHED] fast [..]
+",
+        )
+        .run();
+}
+
 #[cargo_test]
 fn named_config_profile() {
     // Exercises config named profies.
@@ -132,6 +132,57 @@ fn profile_override_bad_settings() {
     }
 }
 
+#[cargo_test]
+fn profile_override_bad_spec() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                [package]
+                name = "foo"
+                version = "0.0.1"
+
+                [profile.dev.package."bar:not-a-version"]
+                opt-level = 1
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+
+    p.cargo("build")
+        .with_status(101)
+        .with_stderr(
+            "\
+[ERROR] failed to parse manifest at `[CWD]/Cargo.toml`
+
+Caused by:
+  invalid package ID specification `bar:not-a-version` in profile override \
+(use `\"*\"` to match all packages): [..] for key `profile.dev.package`
+",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn profile_override_wildcard_spec() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                [package]
+                name = "foo"
+                version = "0.0.1"
+
+                [profile.dev.package."*"]
+                opt-level = 1
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+
+    p.cargo("build").run();
+}
+
 #[cargo_test]
 fn profile_override_hierarchy() {
     // Test that the precedence rules are correct for different types.
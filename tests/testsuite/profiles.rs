@@ -2,7 +2,7 @@
 
 use std::env;
 
-use cargo_test_support::{is_nightly, project};
+use cargo_test_support::{is_nightly, project, Project};
 
 #[cargo_test]
 fn profile_overrides() {
@@ -469,6 +469,92 @@ fn thin_lto_works() {
         .run();
 }
 
+#[cargo_test]
+fn invalid_lto_string() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                [package]
+                name = "foo"
+                version = "0.1.0"
+
+                [profile.release]
+                lto = "maximum"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+
+    p.cargo("build --release")
+        .with_status(101)
+        .with_stderr(
+            "\
+[ERROR] failed to parse manifest at `[CWD]/Cargo.toml`
+
+Caused by:
+  `lto` setting of `maximum` is not a valid setting, \
+must be `true`, `false`, `\"thin\"`, `\"fat\"` or `\"off\"`
+",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn invalid_opt_level_string() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                [package]
+                name = "foo"
+                version = "0.1.0"
+
+                [profile.dev]
+                opt-level = "fast"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+
+    p.cargo("build")
+        .with_status(101)
+        .with_stderr_contains(
+            "  must be `0`, `1`, `2`, `3`, `s` or `z`, but found the string: \"fast\"[..]",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn invalid_opt_level_integer() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                [package]
+                name = "foo"
+                version = "0.1.0"
+
+                [profile.dev]
+                opt-level = 4
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+
+    p.cargo("build")
+        .with_status(101)
+        .with_stderr(
+            "\
+[ERROR] failed to parse manifest at `[CWD]/Cargo.toml`
+
+Caused by:
+  `opt-level` setting of `4` is not a valid setting, must be `0`, `1`, `2`, `3`, `s` or `z`
+",
+        )
+        .run();
+}
+
 #[cargo_test]
 // Strip doesn't work on macos.
 #[cfg_attr(target_os = "macos", ignore)]
@@ -618,6 +704,180 @@ fn strip_accepts_true_to_strip_symbols() {
         .run();
 }
 
+#[cargo_test]
+fn pgo_generate_works() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                cargo-features = ["profile-pgo"]
+
+                [package]
+                name = "foo"
+                version = "0.1.0"
+
+                [profile.release]
+                pgo-generate = "target/pgo-data"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+
+    p.cargo("build --release -v")
+        .masquerade_as_nightly_cargo()
+        .with_stderr(
+            "\
+[COMPILING] foo [..]
+[RUNNING] `rustc [..] -C profile-generate=[CWD]/target/pgo-data [..]`
+[FINISHED] [..]
+",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn pgo_use_works() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                cargo-features = ["profile-pgo"]
+
+                [package]
+                name = "foo"
+                version = "0.1.0"
+
+                [profile.release]
+                pgo-use = "merged.profdata"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        // rustc only warns about profile data it can't read.
+        .file("merged.profdata", "")
+        .build();
+
+    p.cargo("build --release -v")
+        .masquerade_as_nightly_cargo()
+        .with_stderr_contains("[RUNNING] `rustc [..] -C profile-use=[CWD]/merged.profdata [..]`")
+        .with_stderr_contains("[FINISHED] [..]")
+        .run();
+}
+
+#[cargo_test]
+fn pgo_generate_and_use_share_metadata() {
+    // The profile data only applies if symbol names match between the
+    // instrumented and the optimized build.
+    let manifest = |setting: &str| {
+        format!(
+            r#"
+                cargo-features = ["profile-pgo"]
+
+                [package]
+                name = "foo"
+                version = "0.1.0"
+
+                [profile.release]
+                {}
+            "#,
+            setting
+        )
+    };
+    let metadata = |p: &Project| {
+        let output = p
+            .cargo("build --release -v")
+            .masquerade_as_nightly_cargo()
+            .exec_with_output()
+            .unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        let start = stderr.find("-C metadata=").unwrap();
+        stderr[start..]
+            .split_whitespace()
+            .next()
+            .unwrap()
+            .to_string()
+    };
+    let p = project()
+        .file(
+            "Cargo.toml",
+            &manifest(r#"pgo-generate = "target/pgo-data""#),
+        )
+        .file("src/lib.rs", "")
+        .file("merged.profdata", "")
+        .build();
+    let generate = metadata(&p);
+
+    p.change_file("Cargo.toml", &manifest(r#"pgo-use = "merged.profdata""#));
+    let use_ = metadata(&p);
+    assert_eq!(generate, use_);
+}
+
+#[cargo_test]
+fn pgo_requires_cargo_feature() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                [package]
+                name = "foo"
+                version = "0.1.0"
+
+                [profile.release]
+                pgo-use = "merged.profdata"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+
+    p.cargo("build --release -v")
+        .masquerade_as_nightly_cargo()
+        .with_status(101)
+        .with_stderr(
+            "\
+[ERROR] failed to parse manifest at `[CWD]/Cargo.toml`
+
+Caused by:
+  feature `profile-pgo` is required
+
+  consider adding `cargo-features = [\"profile-pgo\"]` to the manifest
+",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn pgo_generate_and_use_are_exclusive() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                cargo-features = ["profile-pgo"]
+
+                [package]
+                name = "foo"
+                version = "0.1.0"
+
+                [profile.release]
+                pgo-generate = "target/pgo-data"
+                pgo-use = "merged.profdata"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+
+    p.cargo("build --release -v")
+        .masquerade_as_nightly_cargo()
+        .with_status(101)
+        .with_stderr(
+            "\
+[ERROR] failed to parse manifest at `[CWD]/Cargo.toml`
+
+Caused by:
+  `pgo-generate` and `pgo-use` cannot both be set in profile `release`
+",
+        )
+        .run();
+}
+
 #[cargo_test]
 fn strip_accepts_false_to_disable_strip() {
     if !is_nightly() {
@@ -159,7 +159,7 @@ fn cargo_read_manifest_with_specified_readme() {
 
 #[cargo_test]
 fn cargo_read_manifest_default_readme() {
-    let readme_filenames = ["README.md", "README.txt", "README"];
+    let readme_filenames = ["README.md", "README.txt", "README", "README.markdown"];
 
     for readme in readme_filenames.iter() {
         let p = project()
@@ -203,3 +203,53 @@ fn cargo_read_manifest_defaults_readme_if_true() {
         .with_json(&manifest_output(r#""README.md""#))
         .run();
 }
+
+#[cargo_test]
+fn cargo_read_manifest_default_readme_case_insensitive() {
+    for readme in ["readme.md", "Readme.markdown"] {
+        let p = project()
+            .file("Cargo.toml", &basic_bin_manifest("foo"))
+            .file(readme, "Sample project")
+            .file("src/foo.rs", &main_file(r#""i am foo""#, &[]))
+            .build();
+
+        p.cargo("read-manifest")
+            .with_json(&manifest_output(&format!(r#""{}""#, readme)))
+            .run();
+    }
+}
+
+// With `readme = true`, the default README files are searched for before
+// falling back to `README.md`.
+#[cargo_test]
+fn cargo_read_manifest_readme_true_finds_default_files() {
+    let p = project()
+        .file("Cargo.toml", &basic_bin_manifest_with_readme("foo", "true"))
+        .file("README.markdown", "Sample project")
+        .file("src/foo.rs", &main_file(r#""i am foo""#, &[]))
+        .build();
+
+    p.cargo("read-manifest")
+        .with_json(&manifest_output(r#""README.markdown""#))
+        .run();
+}
+
+// With `readme = true`, a README in the `docs` directory is found if there is
+// none in the package root, and a root README still takes priority.
+#[cargo_test]
+fn cargo_read_manifest_readme_true_finds_docs_readme() {
+    let p = project()
+        .file("Cargo.toml", &basic_bin_manifest_with_readme("foo", "true"))
+        .file("docs/README.md", "Sample project")
+        .file("src/foo.rs", &main_file(r#""i am foo""#, &[]))
+        .build();
+
+    p.cargo("read-manifest")
+        .with_json(&manifest_output(r#""docs/README.md""#))
+        .run();
+
+    p.change_file("README.txt", "Sample project");
+    p.cargo("read-manifest")
+        .with_json(&manifest_output(r#""README.txt""#))
+        .run();
+}
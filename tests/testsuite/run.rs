@@ -281,6 +281,107 @@ Caused by:
         .run();
 }
 
+#[cargo_test]
+fn specify_default_example() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                cargo-features = ["default-example"]
+
+                [project]
+                name = "foo"
+                version = "0.0.1"
+                authors = []
+                default-example = "a"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .file("examples/a.rs", r#"fn main() { println!("example A"); }"#)
+        .file("examples/b.rs", r#"fn main() { println!("example B"); }"#)
+        .build();
+
+    p.cargo("run --example")
+        .masquerade_as_nightly_cargo()
+        .with_stdout("example A")
+        .run();
+    p.cargo("run --example b")
+        .masquerade_as_nightly_cargo()
+        .with_stdout("example B")
+        .run();
+}
+
+#[cargo_test]
+fn bogus_default_example() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                cargo-features = ["default-example"]
+
+                [project]
+                name = "foo"
+                version = "0.0.1"
+                authors = []
+                default-example = "b"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .file("examples/a.rs", r#"fn main() { println!("example A"); }"#)
+        .build();
+
+    p.cargo("run --example")
+        .masquerade_as_nightly_cargo()
+        .with_status(101)
+        .with_stderr(
+            "\
+[ERROR] failed to parse manifest at `[..]/foo/Cargo.toml`
+
+Caused by:
+  default-example target `b` not found
+
+  <tab>Did you mean `a`?
+",
+        )
+        .run();
+}
+
+#[cargo_test]
+fn default_example_feature_gated() {
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+                [project]
+                name = "foo"
+                version = "0.0.1"
+                authors = []
+                default-example = "a"
+            "#,
+        )
+        .file("src/lib.rs", "")
+        .file("examples/a.rs", r#"fn main() { println!("example A"); }"#)
+        .build();
+
+    p.cargo("run --example")
+        .masquerade_as_nightly_cargo()
+        .with_status(101)
+        .with_stderr(
+            "\
+[ERROR] failed to parse manifest at `[..]/foo/Cargo.toml`
+
+Caused by:
+  the `package.default-example` manifest key is unstable
+
+Caused by:
+  feature `default-example` is required
+
+  consider adding `cargo-features = [\"default-example\"]` to the manifest
+",
+        )
+        .run();
+}
+
 #[cargo_test]
 fn run_example() {
     let p = project()
@@ -258,3 +258,53 @@ fn rust_version_older_than_edition() {
         )
         .run();
 }
+
+#[cargo_test]
+fn rust_version_dependency_newer_than_package() {
+    Package::new("bar", "0.0.1")
+        .cargo_feature("rust-version")
+        .rust_version("1.30")
+        .file("src/lib.rs", "")
+        .publish();
+    Package::new("baz", "0.0.1")
+        .cargo_feature("rust-version")
+        .rust_version("1.40")
+        .file("src/lib.rs", "")
+        .publish();
+
+    let p = project()
+        .file(
+            "Cargo.toml",
+            r#"
+            cargo-features = ["rust-version"]
+
+            [package]
+            name = "foo"
+            version = "0.0.1"
+            rust-version = "1.20"
+
+            [dependencies]
+            bar = "0.0.1"
+            baz = { version = "0.0.1", optional = true }
+        "#,
+        )
+        .file("src/lib.rs", "")
+        .build();
+
+    p.cargo("check")
+        .masquerade_as_nightly_cargo()
+        .with_stderr_contains(
+            "[WARNING] package `foo v0.0.1 ([..])` has a `rust-version` of 1.20, \
+             but its dependency `bar v0.0.1` requires rust-version 1.30",
+        )
+        .with_stderr_does_not_contain("[..]`baz v0.0.1`[..]")
+        .run();
+
+    p.cargo("check --features baz")
+        .masquerade_as_nightly_cargo()
+        .with_stderr_contains(
+            "[WARNING] package `foo v0.0.1 ([..])` has a `rust-version` of 1.20, \
+             but its dependency `baz v0.0.1` requires rust-version 1.40",
+        )
+        .run();
+}